@@ -0,0 +1,74 @@
+//! Rough timings of `FnvHasher`
+//!
+//! Run with `cargo bench --bench fnv`. This doesn't depend on a benchmarking framework so the
+//! numbers are only meant for comparing changes on the same machine.
+
+extern crate hash32;
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use hash32::{FnvHasher, Hasher};
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut() -> u32,
+{
+    // warm up
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{:<24} {:>10.1} ns/iter",
+        name,
+        elapsed.as_secs_f64() * 1e9 / f64::from(ITERATIONS)
+    );
+}
+
+// byte at a time reference implementation
+fn bytewise(bytes: &[u8]) -> u32 {
+    let mut state = hash32::FNV_OFFSET_BASIS_32;
+    for byte in bytes {
+        state ^= u32::from(*byte);
+        state = state.wrapping_mul(hash32::FNV_PRIME_32);
+    }
+    state
+}
+
+fn main() {
+    let bytes = (0..1024).map(|i| i as u8).collect::<Vec<_>>();
+    assert_eq!(
+        {
+            let mut hasher = FnvHasher::default();
+            hasher.write(&bytes);
+            hasher.finish()
+        },
+        bytewise(&bytes)
+    );
+
+    bench("write 1 KiB (bytewise)", || bytewise(black_box(&bytes)));
+    bench("write 1 KiB", || {
+        let mut hasher = FnvHasher::default();
+        hasher.write(black_box(&bytes));
+        hasher.finish()
+    });
+    bench("write(&u32.to_le_bytes)", || {
+        let mut hasher = FnvHasher::default();
+        hasher.write(&black_box(0xdead_beefu32).to_le_bytes());
+        hasher.finish()
+    });
+    bench("write_u32", || {
+        let mut hasher = FnvHasher::default();
+        hasher.write_u32(black_box(0xdead_beef));
+        hasher.finish()
+    });
+}
@@ -0,0 +1,181 @@
+//! Rough timings of `FnvHasher` and `Murmur3Hasher` over 4, 16, 64 and 1024 byte inputs, and of
+//! hashing a `[u32]` in one `write` call versus one element at a time
+//!
+//! Run with `cargo bench --bench hashers`. Like the `fnv` benchmark this doesn't depend on a
+//! benchmarking framework, such as criterion, so the numbers are only meant for comparing changes
+//! on the same machine. Cargo can't make a dev-dependency optional, so criterion, and its
+//! dependencies, would be built by every `cargo test`.
+//!
+//! Compiling this benchmark also runs a rough check against 64-bit and 128-bit integers, which are
+//! emulated, and slow, on most 32-bit targets. The check is purely textual and has known gaps:
+//!
+//! - it only scans the source of `src/fnv.rs` and `src/murmur3.rs`, so it misses wide integers
+//!   reached through other modules, e.g. the provided methods of `src/lib.rs` and
+//!   `src/generic.rs` like `finish_u64`
+//! - it looks for the `u64`, `i64`, `u128` and `i128` tokens, outside of `//` comments, so 64-bit
+//!   operations that don't name one of those types go unnoticed
+//! - it only runs when the benchmarks are built, e.g. by `cargo check --benches` in CI
+//!
+//! It catches the common regression of a wide integer creeping into either hasher, nothing more;
+//! it isn't a check of the generated code.
+
+extern crate hash32;
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use hash32::{FnvHasher, Hash, Hasher, Murmur3Hasher};
+
+const ITERATIONS: u32 = 100_000;
+
+const _: () = assert!(
+    !uses_wide_ints(include_str!("../src/fnv.rs")),
+    "src/fnv.rs uses a 64-bit or 128-bit integer"
+);
+const _: () = assert!(
+    !uses_wide_ints(include_str!("../src/murmur3.rs")),
+    "src/murmur3.rs uses a 64-bit or 128-bit integer"
+);
+
+// Whether `src`, ignoring `//` comments, mentions `u64`, `i64`, `u128` or `i128`
+const fn uses_wide_ints(src: &str) -> bool {
+    let src = src.as_bytes();
+    let mut comment = false;
+    let mut i = 0;
+    while i < src.len() {
+        if src[i] == b'\n' {
+            comment = false;
+        } else if !comment {
+            if src[i] == b'/' && i + 1 < src.len() && src[i + 1] == b'/' {
+                comment = true;
+            } else if is_wide_int(src, i) {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn is_wide_int(src: &[u8], i: usize) -> bool {
+    // not the tail of a longer word; digits and `_` still count so `1u64` and `to_u64` match
+    if (src[i] != b'u' && src[i] != b'i') || (i > 0 && src[i - 1].is_ascii_alphabetic()) {
+        return false;
+    }
+
+    let len = if matches_at(src, i + 1, b"64") {
+        3
+    } else if matches_at(src, i + 1, b"128") {
+        4
+    } else {
+        return false;
+    };
+
+    i + len == src.len() || !(src[i + len].is_ascii_alphanumeric() || src[i + len] == b'_')
+}
+
+const fn matches_at(src: &[u8], start: usize, pattern: &[u8]) -> bool {
+    if start + pattern.len() > src.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < pattern.len() {
+        if src[start + i] != pattern[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Average nanoseconds per call of `f`
+fn time<F>(f: F) -> f64
+where
+    F: Fn() -> u32,
+{
+    // warm up
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS)
+}
+
+fn bench<H, const N: usize>(name: &str)
+where
+    H: Default + Hasher,
+{
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+
+    let ns = time(|| {
+        let mut hasher = H::default();
+        hasher.write(black_box(&bytes));
+        hasher.finish()
+    });
+
+    println!(
+        "{:<8} {:>4} B {:>10.1} ns/iter {:>8.1} MB/s",
+        name,
+        N,
+        ns,
+        N as f64 * 1e3 / ns
+    );
+}
+
+// `u32::hash_slice`, which feeds the whole slice at once, against feeding each element on its own
+fn bench_slice<H>(name: &str)
+where
+    H: Default + Hasher,
+{
+    let mut words = [0u32; 256];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = i as u32;
+    }
+
+    let bulk = || {
+        let mut hasher = H::default();
+        u32::hash_slice(black_box(&words), &mut hasher);
+        hasher.finish()
+    };
+    let one_by_one = || {
+        let mut hasher = H::default();
+        for word in black_box(&words) {
+            hasher.write_u32(*word);
+        }
+        hasher.finish()
+    };
+    assert_eq!(bulk(), one_by_one());
+
+    println!(
+        "{:<8} [u32; 256] slice    {:>10.1} ns/iter",
+        name,
+        time(bulk)
+    );
+    println!(
+        "{:<8} [u32; 256] elements {:>10.1} ns/iter",
+        name,
+        time(one_by_one)
+    );
+}
+
+fn main() {
+    bench::<FnvHasher, 4>("fnv");
+    bench::<FnvHasher, 16>("fnv");
+    bench::<FnvHasher, 64>("fnv");
+    bench::<FnvHasher, 1024>("fnv");
+
+    bench::<Murmur3Hasher, 4>("murmur3");
+    bench::<Murmur3Hasher, 16>("murmur3");
+    bench::<Murmur3Hasher, 64>("murmur3");
+    bench::<Murmur3Hasher, 1024>("murmur3");
+
+    bench_slice::<FnvHasher>("fnv");
+    bench_slice::<Murmur3Hasher>("murmur3");
+}
@@ -6,6 +6,12 @@ main() {
     case $TARGET in
         x86_64-unknown-linux-gnu)
             cargo test
+            cargo test --features alloc
+            cargo test --features core-hash-bridge
+            cargo test --features std
+            cargo test --features test
+            cargo check --benches
+            (cd macros && cargo test)
             ;;
     esac
 
@@ -2,6 +2,8 @@
 extern crate hash32_derive;
 extern crate hash32;
 
+use std::marker::PhantomData;
+
 use hash32::{FnvHasher, Hash, Hasher};
 
 #[derive(Hash32)]
@@ -12,15 +14,82 @@ struct Led {
 #[derive(Hash32)]
 struct Ipv4Addr([u8; 4]);
 
+#[derive(Hash32)]
+struct Cached {
+    key: u16,
+    #[hash32(skip)]
+    hash: u32,
+}
+
+#[derive(Hash32)]
+struct Route {
+    hops: (u16, u8),
+}
+
 #[derive(Hash32)]
 struct Generic<T> {
     inner: T,
 }
 
+#[derive(Hash32)]
+struct Borrowed<'a, T: Copy + 'a, U>
+where
+    U: Clone,
+{
+    key: &'a T,
+    value: U,
+}
+
+#[derive(Hash32)]
+enum Command<T> {
+    Reset,
+    Write(u8, T),
+    Read { addr: u8 },
+}
+
+#[derive(Hash32)]
+#[hash32(bound = "")]
+struct Id<T> {
+    raw: u32,
+    kind: PhantomData<T>,
+}
+
+// doesn't implement `Hash`
+struct Opaque;
+
 fn main() {
     let mut fnv = FnvHasher::default();
     Led { state: true }.hash(&mut fnv);
     Generic { inner: 0 }.hash(&mut fnv);
     Ipv4Addr([127, 0, 0, 1]).hash(&mut fnv);
-    println!("{}", fnv.finish())
+    Route { hops: (1, 2) }.hash(&mut fnv);
+    Borrowed {
+        key: &0u8,
+        value: 1u16,
+    }
+    .hash(&mut fnv);
+    Command::Write(0, 1u16).hash(&mut fnv);
+    Command::<u16>::Read { addr: 0 }.hash(&mut fnv);
+    Command::<u16>::Reset.hash(&mut fnv);
+    println!("{}", fnv.finish());
+
+    // the memoized hash doesn't feed into itself
+    let mut cached = Cached { key: 1, hash: 0 };
+    let mut fnv = FnvHasher::default();
+    cached.hash(&mut fnv);
+    cached.hash = fnv.finish();
+    let mut fnv = FnvHasher::default();
+    cached.hash(&mut fnv);
+    assert_eq!(cached.hash, fnv.finish());
+
+    // `Opaque` doesn't need to implement `Hash`
+    let mut fnv = FnvHasher::default();
+    Id::<Opaque> {
+        raw: 1,
+        kind: PhantomData,
+    }
+    .hash(&mut fnv);
+    let mut manual = FnvHasher::default();
+    1u32.hash(&mut manual);
+    assert_eq!(fnv.finish(), manual.finish());
 }
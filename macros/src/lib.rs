@@ -8,21 +8,40 @@ extern crate syn;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::Tokens;
-use syn::{Data, DeriveInput, Fields, GenericParam, Generics, Ident, IntSuffix, LitInt};
+use syn::{
+    Attribute, Data, DeriveInput, Field, Fields, GenericParam, Generics, Ident, IntSuffix, Lit,
+    LitInt, LitStr, Meta, NestedMeta, Path, Variant, WhereClause,
+};
 
-#[proc_macro_derive(Hash32)]
+#[proc_macro_derive(Hash32, attributes(hash32))]
 pub fn derive_hash32(input: TokenStream) -> TokenStream {
     let input: DeriveInput = syn::parse(input).unwrap();
 
     let name = input.ident;
-    let generics = add_trait_bounds(input.generics);
+    let attrs = container_attrs(&input.attrs);
+    let generics = match attrs.bound {
+        Some(bound) => add_custom_bounds(input.generics, bound),
+        None => add_trait_bounds(input.generics),
+    };
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let const_ = Ident::from(&*format!("__IMPL_HASH32_FOR_{}", name));
-    let hash = compute_hash(&input.data);
+    let hash = compute_hash(name, &input.data);
+    let const_hash = if attrs.konst {
+        let body = compute_const_hash(name, &input.data);
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[doc = "Returns the hash that `FnvHasher::default()` computes for `self`"]
+                #[allow(dead_code)]
+                pub const fn const_hash(&self) -> u32 {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
     quote!(
-        #[allow(non_upper_case_globals)]
-        const #const_: () = {
+        const _: () = {
             extern crate hash32;
 
             impl #impl_generics hash32::Hash for #name #ty_generics #where_clause {
@@ -30,11 +49,61 @@ pub fn derive_hash32(input: TokenStream) -> TokenStream {
                     #hash
                 }
              }
+
+            #const_hash
         };
-    ).into()
+    )
+    .into()
+}
+
+// The `#[hash32(..)]` attributes of the type
+#[derive(Default)]
+struct ContainerAttrs {
+    // `#[hash32(const)]`
+    konst: bool,
+    // `#[hash32(bound = "..")]`
+    bound: Option<WhereClause>,
+}
+
+const CONTAINER_EXPECTED: &str = "expected `#[hash32(const)]` or `#[hash32(bound = \"..\")]`";
+
+fn container_attrs(attrs: &[Attribute]) -> ContainerAttrs {
+    let mut container = ContainerAttrs::default();
+    for attr in attrs {
+        match attr.interpret_meta() {
+            Some(Meta::List(ref list)) if list.ident == "hash32" => {
+                for nested in &list.nested {
+                    match *nested {
+                        NestedMeta::Meta(Meta::Word(ref word)) if word == "const" => {
+                            container.konst = true
+                        }
+                        NestedMeta::Meta(Meta::NameValue(ref nv)) if nv.ident == "bound" => {
+                            match nv.lit {
+                                Lit::Str(ref bound) => container.bound = Some(parse_bound(bound)),
+                                _ => panic!("#[hash32(bound = ..)] expects a string literal"),
+                            }
+                        }
+                        _ => panic!("unknown #[hash32] attribute; {}", CONTAINER_EXPECTED),
+                    }
+                }
+            }
+            Some(ref meta) if meta.name() == "hash32" => {
+                panic!("malformed #[hash32] attribute; {}", CONTAINER_EXPECTED)
+            }
+            _ => {}
+        }
+    }
+    container
 }
 
-// Add a bound `T: Hash` to every type parameter T.
+// Parses the `T: Trait, ..` of `#[hash32(bound = "T: Trait, ..")]`; may be empty
+fn parse_bound(bound: &LitStr) -> WhereClause {
+    syn::parse_str(&format!("where {}", bound.value()))
+        .unwrap_or_else(|_| panic!("#[hash32(bound = ..)] expects `where` clause predicates"))
+}
+
+// Add a bound `T: Hash` to every type parameter T. Lifetimes, const parameters, the existing
+// bounds and the where clause are left untouched.
 fn add_trait_bounds(mut generics: Generics) -> Generics {
     for param in &mut generics.params {
         if let GenericParam::Type(ref mut type_param) = *param {
@@ -44,30 +113,248 @@ fn add_trait_bounds(mut generics: Generics) -> Generics {
     generics
 }
 
-fn compute_hash(data: &Data) -> Tokens {
+// Add the `#[hash32(bound = "..")]` predicates to the where clause instead of the `T: Hash` bounds
+fn add_custom_bounds(mut generics: Generics, bound: WhereClause) -> Generics {
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(bound.predicates);
+    generics
+}
+
+// The `#[hash32(..)]` attributes of a field
+#[derive(Default)]
+struct FieldAttrs {
+    // `#[hash32(skip)]`
+    skip: bool,
+    // `#[hash32(order = N)]`
+    order: Option<u64>,
+    // `#[hash32(with = "path")]`
+    with: Option<Path>,
+}
+
+const FIELD_EXPECTED: &str =
+    "expected `#[hash32(skip)]`, `#[hash32(order = N)]` or `#[hash32(with = \"path\")]`";
+
+fn field_attrs(field: &Field) -> FieldAttrs {
+    let mut attrs = FieldAttrs::default();
+    for attr in &field.attrs {
+        match attr.interpret_meta() {
+            Some(Meta::List(ref list)) if list.ident == "hash32" => {
+                for nested in &list.nested {
+                    match *nested {
+                        NestedMeta::Meta(Meta::Word(ref word)) if word == "skip" => {
+                            attrs.skip = true
+                        }
+                        NestedMeta::Meta(Meta::NameValue(ref nv)) if nv.ident == "order" => {
+                            match nv.lit {
+                                Lit::Int(ref order) => attrs.order = Some(order.value()),
+                                _ => panic!("#[hash32(order = N)] expects an integer"),
+                            }
+                        }
+                        NestedMeta::Meta(Meta::NameValue(ref nv)) if nv.ident == "with" => {
+                            match nv.lit {
+                                Lit::Str(ref path) => {
+                                    attrs.with = Some(path.parse().unwrap_or_else(|_| {
+                                        panic!("#[hash32(with = ..)] expects a path to a function")
+                                    }))
+                                }
+                                _ => panic!("#[hash32(with = ..)] expects a string literal"),
+                            }
+                        }
+                        _ => panic!("unknown #[hash32] attribute; {}", FIELD_EXPECTED),
+                    }
+                }
+            }
+            Some(ref meta) if meta.name() == "hash32" => {
+                panic!("malformed #[hash32] attribute; {}", FIELD_EXPECTED)
+            }
+            _ => {}
+        }
+    }
+    if attrs.skip && (attrs.order.is_some() || attrs.with.is_some()) {
+        panic!("a #[hash32(skip)] field can't also have an `order` or a `with` function")
+    }
+    attrs
+}
+
+// The fields that participate in the hash, paired with their declaration index and their `with`
+// function, in the order they are hashed: first the fields with an explicit `order`, sorted by it,
+// then the rest in declaration order
+fn hashed_fields<'a, I>(fields: I) -> Vec<(usize, &'a Field, Option<Path>)>
+where
+    I: IntoIterator<Item = &'a Field>,
+{
+    let mut ordered = vec![];
+    let mut rest = vec![];
+    for (i, field) in fields.into_iter().enumerate() {
+        let attrs = field_attrs(field);
+        if attrs.skip {
+            continue;
+        }
+        match attrs.order {
+            Some(order) => ordered.push((order, i, field, attrs.with)),
+            None => rest.push((i, field, attrs.with)),
+        }
+    }
+    ordered.sort_by_key(|&(order, _, _, _)| order);
+    for pair in ordered.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            panic!("duplicate #[hash32(order = {})]", pair[0].0)
+        }
+    }
+    ordered
+        .into_iter()
+        .map(|(_, i, field, with)| (i, field, with))
+        .chain(rest)
+        .collect()
+}
+
+// Hashes `value`, a reference to a field, or the value returned by `with(value)`
+fn hash_field(value: Tokens, with: &Option<Path>) -> Tokens {
+    match *with {
+        Some(ref with) => quote!(hash32::Hash::hash(&#with(#value), _h);),
+        None => quote!(hash32::Hash::hash(#value, _h);),
+    }
+}
+
+fn compute_hash(name: Ident, data: &Data) -> Tokens {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let fnames = fields.named.iter().map(|f| f.ident);
+                let hashes = hashed_fields(&fields.named)
+                    .into_iter()
+                    .map(|(_, f, with)| {
+                        let fname = f.ident;
+                        hash_field(quote!(&self.#fname), &with)
+                    });
                 quote! {
-                    #(
-                        hash32::Hash::hash(&self.#fnames, _h);
-                    )*
+                    #(#hashes)*
                 }
             }
             Fields::Unnamed(ref fields) => {
-                let indices = (0..fields.unnamed.len())
-                    .map(|i| LitInt::new(i as u64, IntSuffix::None, Span::call_site()));
+                let hashes = hashed_fields(&fields.unnamed)
+                    .into_iter()
+                    .map(|(i, _, with)| {
+                        let index = LitInt::new(i as u64, IntSuffix::None, Span::call_site());
+                        hash_field(quote!(&self.#index), &with)
+                    });
                 quote! {
-                    #(
-                        hash32::Hash::hash(&self.#indices, _h);
-                    )*
+                    #(#hashes)*
                 }
             }
-            Fields::Unit => quote!{},
+            Fields::Unit => quote! {},
         },
-        Data::Enum(..) | Data::Union(..) => {
-            panic!("#[derive(Hash)] doesn't currently support `enum` and `union`")
+        Data::Enum(ref data) => {
+            let discriminants = discriminants(&data.variants);
+            let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+                let vname = variant.ident;
+                let discriminant = &discriminants[i];
+                match variant.fields {
+                    Fields::Named(ref fields) => {
+                        let hashed = hashed_fields(&fields.named);
+                        let fnames = hashed.iter().map(|&(_, f, _)| f.ident);
+                        let hashes = hashed.iter().map(|&(_, f, ref with)| {
+                            let fname = f.ident;
+                            hash_field(quote!(#fname), with)
+                        });
+                        quote! {
+                            #name::#vname { #(ref #fnames,)* .. } => {
+                                hash32::Hash::hash(&(#discriminant), _h);
+                                #(#hashes)*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(ref fields) => {
+                        let hashed = hashed_fields(&fields.unnamed);
+                        // skipped fields are matched with `_`
+                        let patterns = (0..fields.unnamed.len())
+                            .map(|i| {
+                                if hashed.iter().any(|&(j, _, _)| i == j) {
+                                    let binding = Ident::from(&*format!("__{}", i));
+                                    quote!(ref #binding)
+                                } else {
+                                    quote!(_)
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        let hashes = hashed.iter().map(|&(i, _, ref with)| {
+                            let binding = Ident::from(&*format!("__{}", i));
+                            hash_field(quote!(#binding), with)
+                        });
+                        quote! {
+                            #name::#vname(#(#patterns),*) => {
+                                hash32::Hash::hash(&(#discriminant), _h);
+                                #(#hashes)*
+                            }
+                        }
+                    }
+                    Fields::Unit => quote! {
+                        #name::#vname => {
+                            hash32::Hash::hash(&(#discriminant), _h);
+                        }
+                    },
+                }
+            });
+            quote! {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(..) => panic!("#[derive(Hash32)] doesn't currently support `union`"),
+    }
+}
+
+// The discriminant of every variant, as a `u32` expression. Like the compiler, a variant without an
+// explicit discriminant gets the one of the previous variant plus one, and the first variant `0`
+fn discriminants<'a, I>(variants: I) -> Vec<Tokens>
+where
+    I: IntoIterator<Item = &'a Variant>,
+{
+    let mut base = None;
+    let mut offset = 0;
+    variants
+        .into_iter()
+        .map(|variant| {
+            if let Some((_, ref expr)) = variant.discriminant {
+                base = Some(expr);
+                offset = 0;
+            }
+            let lit = LitInt::new(offset, IntSuffix::U32, Span::call_site());
+            offset += 1;
+            match base {
+                Some(base) => quote!(((#base) as u32).wrapping_add(#lit)),
+                None => quote!(#lit),
+            }
+        })
+        .collect()
+}
+
+// The body of `const_hash`: FNV-1a of the discriminant, which is all the derived `hash` feeds to
+// the hasher for a fieldless enum
+fn compute_const_hash(name: Ident, data: &Data) -> Tokens {
+    let variants = match *data {
+        Data::Enum(ref data) => &data.variants,
+        _ => panic!("#[hash32(const)] is only supported on enums"),
+    };
+    let discriminants = discriminants(variants);
+    let arms = variants.iter().enumerate().map(|(i, variant)| {
+        match variant.fields {
+            Fields::Unit => {}
+            _ => {
+                panic!("#[hash32(const)] is only supported on enums whose variants have no fields")
+            }
+        }
+        let vname = variant.ident;
+        let discriminant = &discriminants[i];
+        quote! {
+            #name::#vname => hash32::fnv1a(&(#discriminant).to_le_bytes()),
+        }
+    });
+    quote! {
+        match *self {
+            #(#arms)*
         }
     }
 }
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash};
+
+#[derive(Hash32)]
+enum Wrapper<T> {
+    Empty,
+    Full(T),
+}
+
+struct NotHash;
+
+fn main() {
+    let mut hasher = FnvHasher::default();
+    Wrapper::Full(NotHash).hash(&mut hasher);
+}
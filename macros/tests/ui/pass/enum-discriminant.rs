@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash, Hasher};
+
+const BASE: u8 = 0x10;
+
+#[derive(Clone, Copy, Hash32)]
+#[hash32(const)]
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    Load = 5,
+    Store,
+    Jump = BASE,
+    Halt,
+}
+
+fn hash<T: Hash>(x: &T) -> u32 {
+    let mut hasher = FnvHasher::default();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let opcodes = [
+        Opcode::Nop,
+        Opcode::Load,
+        Opcode::Store,
+        Opcode::Jump,
+        Opcode::Halt,
+    ];
+    for &opcode in &opcodes {
+        assert_eq!(hash(&opcode), hash(&(opcode as u32)));
+        assert_eq!(opcode.const_hash(), hash(&opcode));
+    }
+    assert_eq!(hash(&Opcode::Store), hash(&6u32));
+    assert_eq!(hash(&Opcode::Halt), hash(&0x11u32));
+}
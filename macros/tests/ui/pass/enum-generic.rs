@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash, Hasher};
+
+#[derive(Hash32)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+}
+
+fn hash<T: Hash>(x: &T) -> u32 {
+    let mut hasher = FnvHasher::default();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    assert_eq!(hash(&Either::<u8, u16>::Left(1)), hash(&(0u32, 1u8)));
+    assert_eq!(
+        hash(&Either::<u8, u16>::Right { value: 1 }),
+        hash(&(1u32, 1u16))
+    );
+}
@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash, Hasher};
+
+#[derive(Hash32)]
+enum Message {
+    Ping,
+    Data { id: u16, payload: [u8; 2] },
+}
+
+fn main() {
+    let mut derived = FnvHasher::default();
+    Message::Data {
+        id: 7,
+        payload: [1, 2],
+    }
+    .hash(&mut derived);
+
+    let mut manual = FnvHasher::default();
+    1u32.hash(&mut manual);
+    7u16.hash(&mut manual);
+    [1u8, 2].hash(&mut manual);
+
+    assert_eq!(derived.finish(), manual.finish());
+
+    let mut ping = FnvHasher::default();
+    Message::Ping.hash(&mut ping);
+    assert_ne!(ping.finish(), derived.finish());
+}
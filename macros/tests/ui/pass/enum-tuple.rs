@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash, Hasher};
+
+#[derive(Hash32)]
+enum Command {
+    Write(u8, u16),
+    Read(u8),
+}
+
+fn main() {
+    let mut derived = FnvHasher::default();
+    Command::Write(1, 2).hash(&mut derived);
+
+    let mut manual = FnvHasher::default();
+    0u32.hash(&mut manual);
+    1u8.hash(&mut manual);
+    2u16.hash(&mut manual);
+
+    assert_eq!(derived.finish(), manual.finish());
+
+    let mut derived = FnvHasher::default();
+    Command::Read(1).hash(&mut derived);
+
+    let mut manual = FnvHasher::default();
+    1u32.hash(&mut manual);
+    1u8.hash(&mut manual);
+
+    assert_eq!(derived.finish(), manual.finish());
+}
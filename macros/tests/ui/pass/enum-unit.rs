@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate hash32_derive;
+extern crate hash32;
+
+use hash32::{FnvHasher, Hash, Hasher};
+
+#[derive(Hash32)]
+enum State {
+    Idle,
+    Busy,
+}
+
+fn main() {
+    let mut derived = FnvHasher::default();
+    State::Busy.hash(&mut derived);
+
+    let mut manual = FnvHasher::default();
+    1u32.hash(&mut manual);
+
+    assert_eq!(derived.finish(), manual.finish());
+
+    let mut idle = FnvHasher::default();
+    State::Idle.hash(&mut idle);
+    assert_ne!(idle.finish(), derived.finish());
+}
@@ -0,0 +1,51 @@
+const MOD: u32 = 65521;
+
+// largest number of bytes that can be summed before `b` overflows a `u32`
+const NMAX: usize = 5552;
+
+/// Adler-32 checksum hasher
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Adler32Hasher, Hasher};
+///
+/// let mut hasher = Adler32Hasher::default();
+/// hasher.write(b"Wikipedia");
+/// assert_eq!(hasher.finish(), 0x11e60398);
+/// ```
+pub struct Hasher {
+    a: u32,
+    b: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher { a: 1, b: 0 }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(NMAX) {
+            for byte in chunk {
+                self.a += u32::from(*byte);
+                self.b += self.a;
+            }
+            self.a %= MOD;
+            self.b %= MOD;
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,50 @@
+use core::ffi::c_char;
+use core::slice;
+
+/// A fixed size C string buffer, like a `char name[16]` field of a C struct
+///
+/// The buffer is hashed like a C string: only the bytes up to the first NUL are fed to the hasher,
+/// followed by a NUL terminator, so a `CBuf` hashes like the `CStr` it contains. Whatever follows
+/// the first NUL is ignored, which means two buffers holding the same name but different garbage in
+/// their padding hash the same. A buffer without a NUL is hashed whole, as if it were terminated
+/// right after its last byte.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::c_char;
+///
+/// use hash32::{CBuf, FnvHasher, Hash, Hasher};
+///
+/// // a `char name[8]` holding "eth0", with garbage after the NUL
+/// let name = [0x65, 0x74, 0x68, 0x30, 0, 0x55, 0x2a, 0].map(|b: u8| b as c_char);
+///
+/// let mut hasher = FnvHasher::default();
+/// CBuf(&name).hash(&mut hasher);
+///
+/// assert_eq!(hasher.finish(), hash32::fnv1a(b"eth0\0"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CBuf<'a>(pub &'a [c_char]);
+
+impl<'a> CBuf<'a> {
+    /// Returns the bytes of the string, up to but not including the first NUL
+    pub fn to_bytes(&self) -> &'a [u8] {
+        // NOTE(unsafe) `c_char` is either `i8` or `u8` so the slice is also a valid `[u8]`
+        let bytes = unsafe { slice::from_raw_parts(self.0.as_ptr() as *const u8, self.0.len()) };
+        match bytes.iter().position(|b| *b == 0) {
+            Some(nul) => &bytes[..nul],
+            None => bytes,
+        }
+    }
+}
+
+impl<'a> ::Hash for CBuf<'a> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: ::Hasher,
+    {
+        state.write(self.to_bytes());
+        state.write_u8(0);
+    }
+}
@@ -0,0 +1,110 @@
+/// XOR checksum hasher
+///
+/// The low byte of the hash is the XOR of all the bytes written so far; the upper 24 bits hold the
+/// number of bytes written, modulo 2<sup>24</sup>, so inputs that only differ by trailing zeros or
+/// by a pair of equal bytes don't collide. This is a quick integrity check for framing, not a
+/// hash function with any collision resistance.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, XorSumHasher};
+///
+/// assert_eq!(XorSumHasher::default().finish(), 0);
+///
+/// let mut hasher = XorSumHasher::default();
+/// hasher.write(b"123456789");
+/// assert_eq!(hasher.finish(), 0x0931);
+///
+/// let bytes = b"123456789";
+/// for i in 0..=bytes.len() {
+///     let mut split = XorSumHasher::default();
+///     split.write(&bytes[..i]);
+///     split.write(&bytes[i..]);
+///     assert_eq!(split.finish(), hasher.finish());
+/// }
+/// ```
+#[derive(Default)]
+pub struct XorHasher {
+    len: u32,
+    xor: u8,
+}
+
+impl ::CountingHasher for XorHasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.len as usize
+    }
+}
+
+impl ::generic::HasherN for XorHasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        (self.len << 8) | u32::from(self.xor)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.xor ^= *byte;
+        }
+        self.len = self.len.wrapping_add(bytes.len() as u32);
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+/// Additive checksum hasher
+///
+/// The hash is the wrapping 32-bit sum of all the bytes written so far. Like
+/// [`XorSumHasher`](struct.XorSumHasher.html) this is meant for quick integrity checks only.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{AddSumHasher, Hasher};
+///
+/// assert_eq!(AddSumHasher::default().finish(), 0);
+///
+/// let mut hasher = AddSumHasher::default();
+/// hasher.write(b"123456789");
+/// assert_eq!(hasher.finish(), 0x01dd);
+///
+/// let bytes = b"123456789";
+/// for i in 0..=bytes.len() {
+///     let mut split = AddSumHasher::default();
+///     split.write(&bytes[..i]);
+///     split.write(&bytes[i..]);
+///     assert_eq!(split.finish(), hasher.finish());
+/// }
+/// ```
+#[derive(Default)]
+pub struct AddHasher {
+    sum: u32,
+}
+
+impl ::generic::HasherN for AddHasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.sum
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.sum = self.sum.wrapping_add(u32::from(*byte));
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
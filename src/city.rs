@@ -0,0 +1,319 @@
+use core::cmp;
+
+use byteorder::{ByteOrder, LE};
+
+use murmur3::fmix;
+
+const C1: u32 = 0xcc9e2d51;
+const C2: u32 = 0x1b873593;
+
+/// Maximum number of bytes for which a `CityHash32Hasher` computes the reference CityHash32
+pub const CAPACITY: usize = 128;
+
+/// Google's CityHash32 hasher
+///
+/// CityHash32 picks its code path based on the total input length and starts its main loop from a
+/// state derived from the last 20 bytes of the input, so it can't be computed incrementally. This
+/// hasher buffers the input it is fed, up to [`CAPACITY`] bytes, and computes the hash in
+/// `finish`.
+///
+/// Longer inputs are hashed in a mode that doesn't match the reference implementation: every time
+/// the buffer is full and more input arrives, the CityHash32 of the buffer is folded into a running
+/// state and the buffer is emptied. `finish` then combines that state with the CityHash32 of the
+/// remaining bytes and the total length. The buffer is always flushed at the same offsets, so the
+/// hash still doesn't depend on how the input is split across `write` calls.
+///
+/// [`CAPACITY`]: constant.CITY_HASH32_CAPACITY.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{CityHash32Hasher, Hasher};
+///
+/// let mut hasher = CityHash32Hasher::default();
+/// hasher.write(b"hello world");
+/// assert_eq!(hasher.finish(), 0x19a7581a);
+///
+/// // computed with the reference C++ implementation, one input per code path
+/// let vectors: &[(&[u8], u32)] = &[
+///     (b"", 0xdc56d17a),
+///     (b"abc", 0x2f635ec7),
+///     (b"\xff\x80", 0xdf606262),
+///     (b"hello world, again", 0x23f57db8),
+///     (b"The quick brown fox jumps over the lazy dog", 0xa339c810),
+/// ];
+/// for &(data, hash) in vectors {
+///     let mut hasher = CityHash32Hasher::default();
+///     hasher.write(data);
+///     assert_eq!(hasher.finish(), hash);
+/// }
+/// ```
+///
+/// Inputs longer than [`CAPACITY`] bytes are hashed too.
+///
+/// ```
+/// use hash32::{CityHash32Hasher, Hasher, CITY_HASH32_CAPACITY};
+///
+/// let data = [0xa5; 1000];
+///
+/// let mut whole = CityHash32Hasher::default();
+/// whole.write(&data);
+///
+/// let mut pieces = CityHash32Hasher::default();
+/// for piece in data.chunks(7) {
+///     pieces.write(piece);
+/// }
+///
+/// assert_eq!(whole.finish(), pieces.finish());
+///
+/// let mut longer = CityHash32Hasher::default();
+/// longer.write(&data[..CITY_HASH32_CAPACITY + 1]);
+/// assert_ne!(longer.finish(), whole.finish());
+/// ```
+pub struct Hasher {
+    buf: [u8; CAPACITY],
+    // number of bytes in `buf`
+    len: usize,
+    // total number of bytes fed to the hasher
+    total: usize,
+    // the folded hashes of the full buffers flushed so far
+    state: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher {
+            buf: [0; CAPACITY],
+            len: 0,
+            total: 0,
+            state: 0,
+        }
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.total
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        let hash = city_hash32(&self.buf[..self.len]);
+        if self.total <= CAPACITY {
+            hash
+        } else {
+            fmix(mur(self.total as u32, mur(hash, self.state)))
+        }
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            if self.len == CAPACITY {
+                // more input than fits in the buffer; fold the full buffer into the state
+                self.state = mur(city_hash32(&self.buf), self.state);
+                self.len = 0;
+            }
+
+            let n = cmp::min(CAPACITY - self.len, bytes.len());
+            self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            self.total = self.total.wrapping_add(n);
+            bytes = &bytes[n..];
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+fn city_hash32(s: &[u8]) -> u32 {
+    match s.len() {
+        0..=4 => hash_len_0_to_4(s),
+        5..=12 => hash_len_5_to_12(s),
+        13..=24 => hash_len_13_to_24(s),
+        _ => hash_len_25_plus(s),
+    }
+}
+
+fn hash_len_0_to_4(s: &[u8]) -> u32 {
+    let mut b = 0u32;
+    let mut c = 9;
+    for byte in s {
+        // NOTE the reference implementation sign extends the bytes
+        b = b
+            .wrapping_mul(C1)
+            .wrapping_add(i32::from(*byte as i8) as u32);
+        c ^= b;
+    }
+    fmix(mur(b, mur(s.len() as u32, c)))
+}
+
+fn hash_len_5_to_12(s: &[u8]) -> u32 {
+    let len = s.len();
+    let mut a = len as u32;
+    let mut b = a.wrapping_mul(5);
+    let mut c = 9u32;
+    let d = b;
+    a = a.wrapping_add(fetch(s, 0));
+    b = b.wrapping_add(fetch(s, len - 4));
+    c = c.wrapping_add(fetch(s, (len >> 1) & 4));
+    fmix(mur(c, mur(b, mur(a, d))))
+}
+
+fn hash_len_13_to_24(s: &[u8]) -> u32 {
+    let len = s.len();
+    let a = fetch(s, (len >> 1) - 4);
+    let b = fetch(s, 4);
+    let c = fetch(s, len - 8);
+    let d = fetch(s, len >> 1);
+    let e = fetch(s, 0);
+    let f = fetch(s, len - 4);
+    let h = len as u32;
+    fmix(mur(f, mur(e, mur(d, mur(c, mur(b, mur(a, h)))))))
+}
+
+fn hash_len_25_plus(s: &[u8]) -> u32 {
+    let len = s.len();
+    let mut h = len as u32;
+    let mut g = C1.wrapping_mul(h);
+    let mut f = g;
+
+    let a0 = pre_mix(fetch(s, len - 4));
+    let a1 = pre_mix(fetch(s, len - 8));
+    let a2 = pre_mix(fetch(s, len - 16));
+    let a3 = pre_mix(fetch(s, len - 12));
+    let a4 = pre_mix(fetch(s, len - 20));
+    h = step(h ^ a0, 19);
+    h = step(h ^ a2, 19);
+    g = step(g ^ a1, 19);
+    g = step(g ^ a3, 19);
+    f = step(f.wrapping_add(a4), 19);
+
+    for block in s[..(len - 1) / 20 * 20].chunks(20) {
+        let a0 = pre_mix(fetch(block, 0));
+        let a1 = fetch(block, 4);
+        let a2 = pre_mix(fetch(block, 8));
+        let a3 = pre_mix(fetch(block, 12));
+        let a4 = fetch(block, 16);
+        h = step(h ^ a0, 18);
+        f = f.wrapping_add(a1).rotate_right(19).wrapping_mul(C1);
+        g = step(g.wrapping_add(a2), 18);
+        h = step(h ^ a3.wrapping_add(a1), 19);
+        g = (g ^ a4).swap_bytes().wrapping_mul(5);
+        h = h.wrapping_add(a4.wrapping_mul(5)).swap_bytes();
+        f = f.wrapping_add(a0);
+
+        // PERMUTE3(f, h, g)
+        let tmp = f;
+        f = g;
+        g = h;
+        h = tmp;
+    }
+
+    g = g.rotate_right(11).wrapping_mul(C1);
+    g = g.rotate_right(17).wrapping_mul(C1);
+    f = f.rotate_right(11).wrapping_mul(C1);
+    f = f.rotate_right(17).wrapping_mul(C1);
+    h = step(h.wrapping_add(g), 19);
+    h = h.rotate_right(17).wrapping_mul(C1);
+    h = step(h.wrapping_add(f), 19);
+    h = h.rotate_right(17).wrapping_mul(C1);
+    h
+}
+
+fn fetch(s: &[u8], i: usize) -> u32 {
+    LE::read_u32(&s[i..])
+}
+
+fn mur(a: u32, h: u32) -> u32 {
+    step(h ^ pre_mix(a), 19)
+}
+
+fn pre_mix(a: u32) -> u32 {
+    a.wrapping_mul(C1).rotate_right(17).wrapping_mul(C2)
+}
+
+fn step(h: u32, r: u32) -> u32 {
+    h.rotate_right(r).wrapping_mul(5).wrapping_add(0xe6546b64)
+}
+
+#[cfg(test)]
+mod tests {
+    use {CityHash32Hasher, Hasher};
+
+    const DATA_LEN: usize = 1000;
+
+    fn data() -> [u8; DATA_LEN] {
+        let mut data = [0; DATA_LEN];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(167).wrapping_add(13);
+        }
+        data
+    }
+
+    fn hash(bytes: &[u8]) -> u32 {
+        let mut hasher = CityHash32Hasher::default();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    // computed with the reference C++ implementation, at the boundaries of its code paths
+    #[test]
+    fn reference_vectors() {
+        let vectors = [
+            (0, 0xdc56d17a),
+            (1, 0x51caa165),
+            (2, 0x98e61747),
+            (3, 0xd9128891),
+            (4, 0x9ffba73a),
+            (5, 0xcec00c7c),
+            (8, 0x00c1530d),
+            (12, 0x658af468),
+            (13, 0x7b3fb513),
+            (16, 0x79364b2c),
+            (24, 0xe0996143),
+            (25, 0x3b1f2bc7),
+            (40, 0xc89ee56d),
+            (45, 0x8ea238f2),
+            (64, 0xd2d367aa),
+            (100, 0x4c156a93),
+            (127, 0xf6937636),
+            (128, 0xda1f2e50),
+        ];
+
+        let data = data();
+        for &(len, expected) in &vectors {
+            assert_eq!(hash(&data[..len]), expected, "len = {}", len);
+        }
+    }
+
+    // the reference CityHash32 of every `CAPACITY` bytes block, folded as documented
+    #[test]
+    fn longer_than_capacity() {
+        let data = data();
+        assert_eq!(hash(&data[..129]), 0x58579068);
+        assert_eq!(hash(&data[..256]), 0x62b8ccad);
+        assert_eq!(hash(&data[..257]), 0x2ab93cc1);
+        assert_eq!(hash(&data), 0xf8b86f9e);
+    }
+
+    #[test]
+    fn streaming_equals_one_shot() {
+        let data = data();
+        for &len in &[0, 3, 11, 24, 25, 128, 129, 300, DATA_LEN] {
+            for chunk in 1..30 {
+                let mut hasher = CityHash32Hasher::default();
+                for piece in data[..len].chunks(chunk) {
+                    hasher.write(piece);
+                }
+                assert_eq!(hasher.finish(), hash(&data[..len]));
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use core::hash;
+
+use BuildHasherDefault;
+
+/// Adapter that makes a 32-bit `Hasher` usable as a [`core::hash::Hasher`][0]
+///
+/// [0]: https://doc.rust-lang.org/core/hash/trait.Hasher.html
+///
+/// This lets types that implement `core::hash::Hash`, but not `hash32::Hash`, be hashed with the
+/// 32-bit hashers of this crate. `finish` returns the 32-bit hash zero extended to a `u64`.
+///
+/// Note that `core::hash::Hash` implementations, including the derived ones, may feed values
+/// through `write_u64`, `write_usize`, etc. Those calls are forwarded to the same methods of the
+/// wrapped hasher, which by default encode them as little endian bytes. On the other hand, the
+/// `core::hash::Hash` implementations of the standard types are not guaranteed to feed the same
+/// bytes as their `hash32::Hash` counterparts.
+///
+/// # Examples
+///
+/// ```
+/// use std::hash::{Hash, Hasher};
+///
+/// use hash32::{CoreHasherAdapter, FnvHasher};
+///
+/// #[derive(Hash)]
+/// struct Point {
+///     x: u16,
+///     y: u16,
+/// }
+///
+/// let mut hasher = CoreHasherAdapter::new(FnvHasher::default());
+/// Point { x: 1, y: 2 }.hash(&mut hasher);
+///
+/// let mut expected = CoreHasherAdapter::new(FnvHasher::default());
+/// expected.write(&[1, 0, 2, 0]);
+///
+/// assert_eq!(hasher.finish(), expected.finish());
+/// assert_eq!(hasher.finish(), u64::from(hash32::fnv1a(&[1, 0, 2, 0])));
+/// ```
+#[derive(Default)]
+pub struct CoreHasherAdapter<H> {
+    hasher: H,
+}
+
+impl<H> CoreHasherAdapter<H>
+where
+    H: ::Hasher,
+{
+    /// Wraps the given `hasher`
+    pub fn new(hasher: H) -> Self {
+        CoreHasherAdapter { hasher }
+    }
+
+    /// Returns the wrapped hasher
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<H> hash::Hasher for CoreHasherAdapter<H>
+where
+    H: ::Hasher,
+{
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hasher.finish_u64()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.hasher.write_u8(i)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.hasher.write_u16(i)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.hasher.write_u32(i)
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.hasher.write_u64(i)
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.hasher.write_u128(i)
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.hasher.write_usize(i)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.hasher.write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.hasher.write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.hasher.write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.hasher.write_i64(i)
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.hasher.write_i128(i)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.hasher.write_isize(i)
+    }
+}
+
+/// `BuildHasherDefault` can also be used where a `core::hash::BuildHasher` is expected, e.g. with
+/// `std::collections::HashMap`
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use hash32::{BuildHasherDefault, FnvHasher};
+///
+/// let mut map: HashMap<&str, u32, BuildHasherDefault<FnvHasher>> = HashMap::default();
+/// map.insert("foo", 1);
+///
+/// assert_eq!(map.get("foo"), Some(&1));
+/// ```
+impl<H> hash::BuildHasher for BuildHasherDefault<H>
+where
+    H: Default + ::Hasher,
+{
+    type Hasher = CoreHasherAdapter<H>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        CoreHasherAdapter::new(H::default())
+    }
+}
+
+/// Hashes a `core::hash::Hash` value with the given 32-bit `hasher`
+///
+/// This wraps `hasher` in a [`CoreHasherAdapter`](struct.CoreHasherAdapter.html), feeds `value`
+/// to it and returns the 32-bit hash.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FnvHasher, Murmur3Hasher};
+///
+/// let key = ("foo", "bar");
+///
+/// assert_eq!(
+///     hash32::hash32_of(&key, FnvHasher::default()),
+///     hash32::hash32_of(&key, FnvHasher::default())
+/// );
+/// assert_ne!(
+///     hash32::hash32_of(&key, FnvHasher::default()),
+///     hash32::hash32_of(&key, Murmur3Hasher::default())
+/// );
+/// assert_ne!(
+///     hash32::hash32_of(&key, FnvHasher::default()),
+///     hash32::hash32_of(&("bar", "foo"), FnvHasher::default())
+/// );
+/// ```
+pub fn hash32_of<T, H>(value: &T, hasher: H) -> u32
+where
+    T: ?Sized + hash::Hash,
+    H: ::Hasher,
+{
+    let mut adapter = CoreHasherAdapter::new(hasher);
+    value.hash(&mut adapter);
+    adapter.into_inner().finish()
+}
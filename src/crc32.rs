@@ -0,0 +1,109 @@
+// reflected form of the IEEE 802.3 polynomial 0x04c11db7
+const POLY: u32 = 0xedb88320;
+
+static TABLE: [u32; 256] = table(POLY);
+
+/// 32-bit CRC (IEEE 802.3) hasher
+///
+/// This is the CRC-32 used by zlib, PNG and Ethernet.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Crc32Hasher, Hasher};
+///
+/// let mut hasher = Crc32Hasher::default();
+/// hasher.write(b"123456789");
+/// assert_eq!(hasher.finish(), 0xcbf43926);
+///
+/// let mut hasher = Crc32Hasher::default();
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0x414fa339);
+/// ```
+pub struct Hasher {
+    state: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher { state: !0 }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        !self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.state = update(&TABLE, self.state, bytes);
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+/// Computes the lookup table of the reflected polynomial `poly`
+pub const fn table(poly: u32) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Feeds `bytes` into the running (non-inverted) `crc`
+#[inline]
+pub fn update(table: &[u32; 256], mut crc: u32, bytes: &[u8]) -> u32 {
+    for byte in bytes {
+        crc = (crc >> 8) ^ table[((crc ^ u32::from(*byte)) & 0xff) as usize];
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use {Crc32Hasher, Hasher};
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn split_at_every_offset() {
+        for i in 0..=CHECK.len() {
+            let (head, tail) = CHECK.split_at(i);
+            let mut hasher = Crc32Hasher::default();
+            hasher.write(head);
+            hasher.write(tail);
+            assert_eq!(hasher.finish(), 0xcbf43926, "split at {}", i);
+        }
+    }
+
+    #[test]
+    fn chunked() {
+        for chunk in 1..=CHECK.len() {
+            let mut hasher = Crc32Hasher::default();
+            for piece in CHECK.chunks(chunk) {
+                hasher.write(piece);
+            }
+            assert_eq!(hasher.finish(), 0xcbf43926, "chunks of {}", chunk);
+        }
+    }
+}
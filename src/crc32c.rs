@@ -0,0 +1,77 @@
+use crc32;
+
+// reflected form of the Castagnoli polynomial 0x1edc6f41
+const POLY: u32 = 0x82f63b78;
+
+static TABLE: [u32; 256] = crc32::table(POLY);
+
+/// 32-bit CRC-32C (Castagnoli) hasher
+///
+/// This is the CRC used by SCTP and iSCSI.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Crc32cHasher, Hasher};
+///
+/// let mut hasher = Crc32cHasher::default();
+/// hasher.write(b"123456789");
+/// assert_eq!(hasher.finish(), 0xe3069283);
+/// ```
+pub struct Hasher {
+    state: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher { state: !0 }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        !self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.state = crc32::update(&TABLE, self.state, bytes);
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Crc32cHasher, Hasher};
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn split_at_every_offset() {
+        for i in 0..=CHECK.len() {
+            let (head, tail) = CHECK.split_at(i);
+            let mut hasher = Crc32cHasher::default();
+            hasher.write(head);
+            hasher.write(tail);
+            assert_eq!(hasher.finish(), 0xe3069283, "split at {}", i);
+        }
+    }
+
+    #[test]
+    fn chunked() {
+        for chunk in 1..=CHECK.len() {
+            let mut hasher = Crc32cHasher::default();
+            for piece in CHECK.chunks(chunk) {
+                hasher.write(piece);
+            }
+            assert_eq!(hasher.finish(), 0xe3069283, "chunks of {}", chunk);
+        }
+    }
+}
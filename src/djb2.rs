@@ -0,0 +1,97 @@
+const SEED: u32 = 5381;
+
+/// Dan Bernstein's djb2 hasher, XOR variant
+///
+/// Each byte is mixed in as `hash = hash * 33 ^ byte`; this is the variant Bernstein later
+/// recommended and is sometimes called djb2a. See [`Djb2AddHasher`] for the original additive
+/// variant.
+///
+/// [`Djb2AddHasher`]: struct.Djb2AddHasher.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Djb2Hasher, Hasher};
+///
+/// assert_eq!(Djb2Hasher::default().finish(), 5381);
+///
+/// let mut hasher = Djb2Hasher::default();
+/// hasher.write(b"a");
+/// assert_eq!(hasher.finish(), 177604);
+/// ```
+pub struct Hasher {
+    state: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher { state: SEED }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = self.state.wrapping_mul(33) ^ u32::from(*byte);
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+/// Dan Bernstein's djb2 hasher, original additive variant
+///
+/// Each byte is mixed in as `hash = hash * 33 + byte`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Djb2AddHasher, Hasher};
+///
+/// assert_eq!(Djb2AddHasher::default().finish(), 5381);
+///
+/// let mut hasher = Djb2AddHasher::default();
+/// hasher.write(b"a");
+/// assert_eq!(hasher.finish(), 177670);
+/// ```
+pub struct AddHasher {
+    state: u32,
+}
+
+impl Default for AddHasher {
+    fn default() -> Self {
+        AddHasher { state: SEED }
+    }
+}
+
+impl ::generic::HasherN for AddHasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = self.state.wrapping_mul(33).wrapping_add(u32::from(*byte));
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
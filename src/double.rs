@@ -0,0 +1,91 @@
+use {Hash, SeededHasher};
+
+// arbitrary odd constant (2^32 / golden ratio) so the second hash is seeded far from the first
+const SEED2: u32 = 0x9e37_79b9;
+
+/// Derives any number of hash values from two seeded hashes of a key
+///
+/// This implements the double hashing scheme used by Bloom filters and open addressing tables:
+/// the `i`-th hash of a key is `h1 + i * h2` (mod 2<sup>32</sup>), where `h1` and `h2` hash the key
+/// with the seeds `0` and `0x9e3779b9`. The key is only hashed twice, no matter how many probes are
+/// needed.
+///
+/// `h2` is forced to be odd so that, for a table of 2<sup>k</sup> slots, the first 2<sup>k</sup>
+/// probes `nth(i) % 2^k` visit every slot exactly once. Reduce the value modulo the size of the
+/// table, or the number of bits of the filter, to get an index.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{DoubleHasher, Murmur3Hasher};
+///
+/// let probes = DoubleHasher::new::<Murmur3Hasher, _>("foo");
+///
+/// let mut seen = [false; 64];
+/// for i in 0..64 {
+///     let index = probes.nth(i) as usize % 64;
+///     assert!(!seen[index]);
+///     seen[index] = true;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DoubleHasher {
+    h1: u32,
+    h2: u32,
+}
+
+impl DoubleHasher {
+    /// Hashes `key` twice with `H`
+    pub fn new<H, T>(key: &T) -> Self
+    where
+        H: SeededHasher,
+        T: ?Sized + Hash,
+    {
+        DoubleHasher {
+            h1: hash_with_seed::<H, T>(key, 0),
+            h2: hash_with_seed::<H, T>(key, SEED2) | 1,
+        }
+    }
+
+    /// Returns the `i`-th hash value of the key, `h1 + i * h2`
+    #[inline]
+    pub fn nth(&self, i: u32) -> u32 {
+        self.h1.wrapping_add(i.wrapping_mul(self.h2))
+    }
+}
+
+/// Returns the `i`-th hash value of `key` under the double hashing scheme
+///
+/// Shorthand for `DoubleHasher::new::<H, T>(key).nth(i)`. Use [`DoubleHasher`] directly to compute
+/// several values without rehashing the key.
+///
+/// [`DoubleHasher`]: struct.DoubleHasher.html
+///
+/// ```
+/// use hash32::{DoubleHasher, FnvHasher};
+///
+/// let probes = DoubleHasher::new::<FnvHasher, _>("foo");
+///
+/// assert_eq!(hash32::double_hash::<FnvHasher, _>("foo", 3), probes.nth(3));
+/// assert_ne!(
+///     hash32::double_hash::<FnvHasher, _>("foo", 0),
+///     hash32::double_hash::<FnvHasher, _>("foo", 1)
+/// );
+/// ```
+pub fn double_hash<H, T>(key: &T, i: u32) -> u32
+where
+    H: SeededHasher,
+    T: ?Sized + Hash,
+{
+    DoubleHasher::new::<H, T>(key).nth(i)
+}
+
+fn hash_with_seed<H, T>(key: &T, seed: u32) -> u32
+where
+    H: SeededHasher,
+    T: ?Sized + Hash,
+{
+    let mut hasher = H::with_seed(seed);
+    key.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,45 @@
+/// PJW hasher, as used in ELF symbol tables
+///
+/// The resulting hash always fits in 28 bits.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{ElfHasher, Hasher};
+///
+/// let mut hasher = ElfHasher::default();
+/// hasher.write(b"printf");
+/// assert_eq!(hasher.finish(), 0x077905a6);
+///
+/// let mut hasher = ElfHasher::default();
+/// hasher.write(b"_start");
+/// assert_eq!(hasher.finish(), 0x066aa894);
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = (self.state << 4).wrapping_add(u32::from(*byte));
+            let high = self.state & 0xf000_0000;
+            self.state ^= high >> 24;
+            self.state &= !high;
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
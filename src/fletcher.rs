@@ -0,0 +1,131 @@
+const MOD: u32 = 65535;
+
+// largest number of words that can be summed before `b` overflows a `u32`
+const NMAX: u32 = 359;
+
+/// Fletcher-32 checksum hasher
+///
+/// The input is consumed as little endian 16-bit words; an odd trailing byte is padded with a zero
+/// byte.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Fletcher32Hasher, Hasher};
+///
+/// let mut hasher = Fletcher32Hasher::default();
+/// hasher.write(b"abcde");
+/// assert_eq!(hasher.finish(), 0xf04fc729);
+///
+/// let mut hasher = Fletcher32Hasher::default();
+/// hasher.write(b"abcdef");
+/// assert_eq!(hasher.finish(), 0x56502d2a);
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    a: u32,
+    b: u32,
+    // leftover byte of an incomplete word
+    byte: Option<u8>,
+    // number of words summed since the last modulo reduction
+    words: u32,
+}
+
+impl Hasher {
+    fn push(&mut self, word: u16) {
+        self.a += u32::from(word);
+        self.b += self.a;
+        self.words += 1;
+
+        if self.words == NMAX {
+            self.a %= MOD;
+            self.b %= MOD;
+            self.words = 0;
+        }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        let (mut a, mut b) = (self.a, self.b);
+        if let Some(byte) = self.byte {
+            a += u32::from(byte);
+            b += a;
+        }
+
+        ((b % MOD) << 16) | (a % MOD)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+
+        if let Some(low) = self.byte {
+            match bytes.split_first() {
+                Some((high, rest)) => {
+                    self.push(u16::from(low) | u16::from(*high) << 8);
+                    self.byte = None;
+                    bytes = rest;
+                }
+                None => return,
+            }
+        }
+
+        for word in bytes.chunks(2) {
+            if word.len() == 2 {
+                self.push(u16::from(word[0]) | u16::from(word[1]) << 8);
+            } else {
+                self.byte = Some(word[0]);
+            }
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Fletcher32Hasher, Hasher};
+
+    const VECTORS: &[(&[u8], u32)] = &[(b"abcde", 0xf04fc729), (b"abcdef", 0x56502d2a)];
+
+    #[test]
+    fn split_at_every_offset() {
+        for &(data, expected) in VECTORS {
+            for i in 0..=data.len() {
+                for j in i..=data.len() {
+                    let mut hasher = Fletcher32Hasher::default();
+                    hasher.write(&data[..i]);
+                    hasher.write(&data[i..j]);
+                    hasher.write(&data[j..]);
+                    assert_eq!(hasher.finish(), expected, "split at {} and {}", i, j);
+                }
+            }
+        }
+    }
+
+    // long enough to go through the modulo reduction, with the words straddling the writes
+    #[test]
+    fn odd_chunks() {
+        let mut data = [0; 1000];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(31).wrapping_add(7);
+        }
+
+        let mut whole = Fletcher32Hasher::default();
+        whole.write(&data);
+        assert_eq!(whole.finish(), 0x90b41788);
+
+        for &chunk in &[1, 3, 5, 7, 359, 719] {
+            let mut hasher = Fletcher32Hasher::default();
+            for piece in data.chunks(chunk) {
+                hasher.write(piece);
+            }
+            assert_eq!(hasher.finish(), whole.finish(), "chunks of {}", chunk);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use core::fmt;
+
+/// Adapter that implements `core::fmt::Write` by feeding the formatted text to a `Hasher`
+///
+/// This hashes formatted output without allocating a string. The text is fed as is, in the order
+/// it's formatted, so the hash equals the one of writing the equivalent bytes in one go, as long as
+/// the hasher is not sensitive to how its input is split. Unlike hashing a `str`, no terminator is
+/// fed.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use hash32::{FnvHasher, HashFmtWriter, Hasher};
+///
+/// let mut hasher = FnvHasher::default();
+/// write!(HashFmtWriter::new(&mut hasher), "{}-{}", 42, "foo").unwrap();
+///
+/// assert_eq!(hasher.finish(), hash32::fnv1a(b"42-foo"));
+/// ```
+pub struct HashFmtWriter<'a, H>
+where
+    H: 'a + ::Hasher,
+{
+    hasher: &'a mut H,
+}
+
+impl<'a, H> HashFmtWriter<'a, H>
+where
+    H: 'a + ::Hasher,
+{
+    /// Wraps the given `hasher`
+    pub fn new(hasher: &'a mut H) -> Self {
+        HashFmtWriter { hasher }
+    }
+}
+
+impl<'a, H> fmt::Write for HashFmtWriter<'a, H>
+where
+    H: 'a + ::Hasher,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.hasher.write(s.as_bytes());
+        Ok(())
+    }
+}
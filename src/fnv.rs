@@ -1,18 +1,273 @@
-const BASIS: u32 = 0x811c9dc5;
-const PRIME: u32 = 0x1000193;
+use core::marker::PhantomData;
 
-/// 32-bit Fowler-Noll-Vo hasher
-pub struct Hasher {
+/// 32-bit FNV offset basis
+pub const FNV_OFFSET_BASIS_32: u32 = 0x811c9dc5;
+
+/// 32-bit FNV prime
+pub const FNV_PRIME_32: u32 = 0x01000193;
+
+/// Order in which a [`Fnv`](struct.Fnv.html) hasher combines each byte with its state
+///
+/// This trait is implemented by the [`Fnv1`](struct.Fnv1.html) and [`Fnv1a`](struct.Fnv1a.html)
+/// markers. The variant is picked at compile time so there's no runtime branch.
+pub trait FnvVariant {
+    /// Mixes `byte` into `state`
+    fn step(state: u32, byte: u8) -> u32;
+}
+
+/// FNV-1 marker: multiply by the FNV prime, then XOR the byte
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Fnv1;
+
+impl FnvVariant for Fnv1 {
+    #[inline]
+    fn step(state: u32, byte: u8) -> u32 {
+        state.wrapping_mul(FNV_PRIME_32) ^ u32::from(byte)
+    }
+}
+
+/// FNV-1a marker: XOR the byte, then multiply by the FNV prime
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Fnv1a;
+
+impl FnvVariant for Fnv1a {
+    #[inline]
+    fn step(state: u32, byte: u8) -> u32 {
+        (state ^ u32::from(byte)).wrapping_mul(FNV_PRIME_32)
+    }
+}
+
+/// 32-bit Fowler-Noll-Vo hasher, generic over the FNV variant `V`
+///
+/// [`FnvHasher`](type.FnvHasher.html) and [`Fnv1Hasher`](type.Fnv1Hasher.html) are aliases of
+/// this type for the FNV-1a and FNV-1 variants, respectively.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Fnv, Fnv1, Fnv1a, Hasher};
+///
+/// // reference vectors from the FNV test suite
+/// let vectors: &[(&[u8], u32, u32)] = &[
+///     (b"", 0x811c9dc5, 0x811c9dc5),
+///     (b"a", 0x050c5d7e, 0xe40c292c),
+///     (b"foobar", 0x31f0b262, 0xbf9cf968),
+/// ];
+///
+/// for &(input, fnv1, fnv1a) in vectors {
+///     let mut hasher = Fnv::<Fnv1>::default();
+///     hasher.write(input);
+///     assert_eq!(hasher.finish(), fnv1);
+///
+///     let mut hasher = Fnv::<Fnv1a>::default();
+///     hasher.write(input);
+///     assert_eq!(hasher.finish(), fnv1a);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fnv<V = Fnv1a> {
+    len: usize,
     state: u32,
+    variant: PhantomData<V>,
 }
 
-impl Default for Hasher {
+/// 32-bit Fowler-Noll-Vo hasher, FNV-1a variant
+///
+/// Each byte is XOR-ed into the state *before* the multiplication by the FNV prime. See
+/// [`Fnv1Hasher`] for the FNV-1 variant.
+///
+/// [`Fnv1Hasher`]: type.Fnv1Hasher.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FnvHasher, Hasher};
+///
+/// let mut hasher = FnvHasher::default();
+/// hasher.write(b"foobar");
+/// assert_eq!(hasher.finish(), 0xbf9cf968);
+/// ```
+///
+/// The state can be copied to hash several inputs that share a prefix.
+///
+/// ```
+/// use hash32::{FnvHasher, Hasher};
+///
+/// let mut prefix = FnvHasher::default();
+/// prefix.write(b"foo");
+///
+/// let mut fork = prefix;
+/// fork.write(b"bar");
+/// prefix.write(b"bar");
+/// assert_eq!(fork, prefix);
+/// assert_eq!(fork.finish(), 0xbf9cf968);
+/// ```
+///
+/// The output doesn't depend on how the input is fed; integers written with `write_u16` and
+/// `write_u32` hash like their little endian bytes.
+///
+/// ```
+/// use hash32::{FnvHasher, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// a.write_u16(0x0102);
+/// a.write_u32(0x0304_0506);
+///
+/// let mut b = FnvHasher::default();
+/// b.write(&[0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// assert_eq!(a.finish(), hash32::fnv1a(&[0x02, 0x01, 0x06, 0x05, 0x04, 0x03]));
+///
+/// let bytes = b"The quick brown fox jumps over the lazy dog";
+/// for i in 0..=bytes.len() {
+///     let mut hasher = FnvHasher::default();
+///     hasher.write(&bytes[..i]);
+///     assert_eq!(hasher.finish(), hash32::fnv1a(&bytes[..i]));
+/// }
+/// ```
+pub type Hasher = Fnv<Fnv1a>;
+
+/// 32-bit Fowler-Noll-Vo hasher, FNV-1 variant
+///
+/// Each byte is XOR-ed into the state *after* the multiplication by the FNV prime. See
+/// [`FnvHasher`] for the FNV-1a variant.
+///
+/// [`FnvHasher`]: type.FnvHasher.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Fnv1Hasher, FnvHasher, Hasher};
+///
+/// let mut fnv1 = Fnv1Hasher::default();
+/// fnv1.write(b"foobar");
+/// assert_eq!(fnv1.finish(), 0x31f0b262);
+///
+/// let mut fnv1a = FnvHasher::default();
+/// fnv1a.write(b"foobar");
+/// assert_ne!(fnv1.finish(), fnv1a.finish());
+/// ```
+pub type Fnv1Hasher = Fnv<Fnv1>;
+
+impl<V> Fnv<V>
+where
+    V: FnvVariant,
+{
+    /// Creates a hasher that uses `key` as its offset basis
+    ///
+    /// Different keys give different hash functions. `with_key(0x811c9dc5)`, the standard offset
+    /// basis, is equivalent to `Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let mut a = FnvHasher::with_key(1);
+    /// a.write(b"foobar");
+    ///
+    /// let mut b = FnvHasher::with_key(2);
+    /// b.write(b"foobar");
+    ///
+    /// assert_ne!(a.finish(), b.finish());
+    ///
+    /// let mut c = FnvHasher::with_key(0x811c9dc5);
+    /// c.write(b"foobar");
+    ///
+    /// assert_eq!(c.finish(), 0xbf9cf968);
+    /// ```
+    pub fn with_key(key: u32) -> Self {
+        Fnv {
+            len: 0,
+            state: key,
+            variant: PhantomData,
+        }
+    }
+
+    /// Returns the hash after applying the MurmurHash3 `fmix32` finalizer to it
+    ///
+    /// FNV doesn't have a finalization step so the bits of its hash are not equally well mixed;
+    /// e.g. the low bits only depend on the low bits of the input bytes. `fmix32` makes every bit
+    /// of the hash depend on every bit of the state, so the buckets of a table are filled about as
+    /// evenly as with random hashes, whatever the structure of the keys.
+    ///
+    /// That is not always an improvement. Keys made of several small fields, like coordinates,
+    /// cluster with plain FNV and are spread out by the finalizer. But sequential integers already
+    /// fill the buckets *more* evenly than random hashes with plain FNV: hashing `0..1000` as
+    /// `u32`s into 1024 buckets uses 714 buckets with `finish` and 643 with `finish_avalanched`.
+    ///
+    /// The streaming state is not affected but the result is **not** the FNV hash of the input:
+    /// it's a different value than the one returned by `finish`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hash, Hasher};
+    ///
+    /// // number of the 1024 buckets used by `finish` and `finish_avalanched`, for 1000 keys
+    /// fn used_buckets<T: Hash>(keys: impl Iterator<Item = T>) -> (usize, usize) {
+    ///     let mut plain = [false; 1024];
+    ///     let mut avalanched = [false; 1024];
+    ///     for key in keys {
+    ///         let mut hasher = FnvHasher::default();
+    ///         key.hash(&mut hasher);
+    ///
+    ///         plain[hasher.finish() as usize % 1024] = true;
+    ///         avalanched[hasher.finish_avalanched() as usize % 1024] = true;
+    ///     }
+    ///
+    ///     let count = |buckets: &[bool]| buckets.iter().filter(|x| **x).count();
+    ///     (count(&plain), count(&avalanched))
+    /// }
+    ///
+    /// // coordinates cluster with plain FNV
+    /// assert_eq!(used_buckets((0..1000u16).map(|i| (i % 32, i / 32))), (479, 641));
+    ///
+    /// // sequential integers don't
+    /// assert_eq!(used_buckets(0..1000u32), (714, 643));
+    /// ```
+    pub fn finish_avalanched(&self) -> u32 {
+        ::murmur3::fmix(self.state)
+    }
+}
+
+impl<V> Default for Fnv<V>
+where
+    V: FnvVariant,
+{
     fn default() -> Self {
-        Hasher { state: BASIS }
+        Fnv::with_key(FNV_OFFSET_BASIS_32)
+    }
+}
+
+/// The seed is XOR-ed into the offset basis so a seed of `0` is equivalent to `Default`
+impl<V> ::SeededHasher for Fnv<V>
+where
+    V: FnvVariant,
+{
+    fn with_seed(seed: u32) -> Self {
+        Fnv::with_key(FNV_OFFSET_BASIS_32 ^ seed)
     }
 }
 
-impl ::Hasher for Hasher {
+impl<V> ::CountingHasher for Fnv<V>
+where
+    V: FnvVariant,
+{
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.len
+    }
+}
+
+// FNV is inherently byte serial; the fast paths below only avoid the slice bookkeeping
+impl<V> ::generic::HasherN for Fnv<V>
+where
+    V: FnvVariant,
+{
+    type Output = u32;
+
     #[inline]
     fn finish(&self) -> u32 {
         self.state
@@ -20,9 +275,146 @@ impl ::Hasher for Hasher {
 
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        for byte in bytes {
-            self.state ^= u32::from(*byte);
-            self.state = self.state.wrapping_mul(PRIME);
+        let mut state = self.state;
+        let mut words = bytes.chunks_exact(4);
+        for word in &mut words {
+            state = V::step(state, word[0]);
+            state = V::step(state, word[1]);
+            state = V::step(state, word[2]);
+            state = V::step(state, word[3]);
         }
+        for byte in words.remainder() {
+            state = V::step(state, *byte);
+        }
+        self.state = state;
+        self.len = self.len.wrapping_add(bytes.len());
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.state = V::step(self.state, i);
+        self.len = self.len.wrapping_add(1);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        let mut state = self.state;
+        state = V::step(state, i as u8);
+        state = V::step(state, (i >> 8) as u8);
+        self.state = state;
+        self.len = self.len.wrapping_add(2);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        let mut state = self.state;
+        state = V::step(state, i as u8);
+        state = V::step(state, (i >> 8) as u8);
+        state = V::step(state, (i >> 16) as u8);
+        state = V::step(state, (i >> 24) as u8);
+        self.state = state;
+        self.len = self.len.wrapping_add(4);
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+/// FNV-1a hasher with a salt mixed into its initial state
+///
+/// The salt is fed to the hasher, as its 4 little endian bytes, before any input, so every
+/// salt gives a different FNV-1a starting state. Use a [`BuildHasherSeeded`], whose seed is the
+/// salt, to give each map its own salt, e.g. one read from a hardware RNG at startup; keys chosen
+/// to collide under one salt then don't, in general, collide under another.
+///
+/// This raises the bar for collision flooding attacks against maps keyed by untrusted input but
+/// it is **not** cryptographically strong: FNV is not a keyed hash function and an attacker that
+/// can observe hashes, or timings, may still recover the salt or find collisions.
+///
+/// [`BuildHasherSeeded`]: struct.BuildHasherSeeded.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{BuildHasher, BuildHasherSeeded, FnvHasher, Hasher, SaltedFnvHasher};
+///
+/// let a = BuildHasherSeeded::<SaltedFnvHasher>::new(0x1234_5678);
+/// let b = BuildHasherSeeded::<SaltedFnvHasher>::new(0x9abc_def0);
+///
+/// assert_eq!(a.hash_one("key"), a.hash_one("key"));
+/// assert_ne!(a.hash_one("key"), b.hash_one("key"));
+///
+/// // equivalent to prefixing the input with the salt
+/// let mut salted = SaltedFnvHasher::with_salt(0x1234_5678);
+/// salted.write(b"key");
+///
+/// let mut prefixed = FnvHasher::default();
+/// prefixed.write(&[0x78, 0x56, 0x34, 0x12]);
+/// prefixed.write(b"key");
+///
+/// assert_eq!(salted.finish(), prefixed.finish());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaltedHasher {
+    inner: Hasher,
+}
+
+impl SaltedHasher {
+    /// Creates a hasher that mixes `salt` into its initial state
+    ///
+    /// `with_salt(0)` is equivalent to `Default`.
+    pub fn with_salt(salt: u32) -> Self {
+        let mut inner = Hasher::default();
+        ::Hasher::write_u32(&mut inner, salt);
+        SaltedHasher { inner }
+    }
+}
+
+impl Default for SaltedHasher {
+    fn default() -> Self {
+        SaltedHasher::with_salt(0)
+    }
+}
+
+/// The seed is used as the salt
+impl ::SeededHasher for SaltedHasher {
+    fn with_seed(seed: u32) -> Self {
+        SaltedHasher::with_salt(seed)
+    }
+}
+
+impl ::generic::HasherN for SaltedHasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.inner.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.write(bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.inner.write_u8(i)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.inner.write_u16(i)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.inner.write_u32(i)
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
     }
 }
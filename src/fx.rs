@@ -0,0 +1,78 @@
+use byteorder::{ByteOrder, LE};
+
+// 2^32 / golden ratio; an odd constant with well distributed bits, same as the one used by the
+// 32-bit version of rustc's `FxHasher`
+const SEED: u32 = 0x9e3779b9;
+
+/// 32-bit version of rustc's `FxHasher`
+///
+/// Input is consumed one word at a time, so this hasher is very fast for small integer keys but
+/// splitting the same bytes across several `write` calls can change the resulting hash.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FxHasher32, Hasher};
+///
+/// let mut hasher = FxHasher32::default();
+/// hasher.write(&[42, 0, 0, 0]);
+/// assert_eq!(hasher.finish(), 42u32.wrapping_mul(0x9e3779b9));
+///
+/// // integers up to 32 bits are a single word, the same as writing their bytes
+/// let mut hasher = FxHasher32::default();
+/// hasher.write_u16(42);
+/// assert_eq!(hasher.finish(), 42u32.wrapping_mul(0x9e3779b9));
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+}
+
+impl Hasher {
+    #[inline]
+    fn add_to_hash(&mut self, word: u32) {
+        self.state = (self.state.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while bytes.len() >= 4 {
+            self.add_to_hash(LE::read_u32(bytes));
+            bytes = &bytes[4..];
+        }
+
+        if bytes.len() >= 2 {
+            self.add_to_hash(u32::from(LE::read_u16(bytes)));
+            bytes = &bytes[2..];
+        }
+
+        if let Some(byte) = bytes.first() {
+            self.add_to_hash(u32::from(*byte));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(u32::from(i))
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(u32::from(i))
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i)
+    }
+}
@@ -0,0 +1,257 @@
+//! Hashing interfaces that are generic over the width of the computed hash
+//!
+//! This is a preview of the `core::hash::Hasher` generalization described in the crate level
+//! documentation, so that code can be written against the width generic API today. These traits
+//! live in their own module because their methods share names with the ones of
+//! [`Hasher`](../trait.Hasher.html); importing both traits into the same scope makes method calls
+//! ambiguous.
+
+/// A hasher that is generic over the width of the computed hash
+///
+/// Every hasher of this crate implements it with `Output = u32`, and every `HasherN<Output = u32>`
+/// implements the 32-bit [`Hasher`](../trait.Hasher.html) through a blanket implementation, which
+/// keeps `Hasher` working as a shorthand for the 32-bit case. A new 32-bit hasher can therefore be
+/// written against `HasherN` and used with everything that expects a `Hasher`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::generic::HasherN;
+/// use hash32::FnvHasher;
+///
+/// fn digest<H: HasherN>(mut hasher: H, bytes: &[u8]) -> H::Output {
+///     hasher.write(bytes);
+///     hasher.finish()
+/// }
+///
+/// let mut hasher = FnvHasher::default();
+/// hash32::Hasher::write(&mut hasher, b"foobar");
+///
+/// assert_eq!(digest(FnvHasher::default(), b"foobar"), hash32::Hasher::finish(&hasher));
+/// ```
+///
+/// Implementing `HasherN<Output = u32>` is enough to hash any `hash32::Hash` value.
+///
+/// ```
+/// use hash32::generic::HasherN;
+/// use hash32::Hash;
+///
+/// // the sum of the bytes
+/// #[derive(Default)]
+/// struct Sum(u32);
+///
+/// impl HasherN for Sum {
+///     type Output = u32;
+///
+///     fn finish(&self) -> u32 {
+///         self.0
+///     }
+///
+///     fn write(&mut self, bytes: &[u8]) {
+///         for byte in bytes {
+///             self.0 += u32::from(*byte);
+///         }
+///     }
+/// }
+///
+/// let mut sum = Sum::default();
+/// (1u8, 0x0203u16).hash(&mut sum);
+/// assert_eq!(hash32::Hasher::finish(&sum), 6);
+/// ```
+pub trait HasherN {
+    /// The type of the computed hash
+    type Output;
+
+    /// Returns the hash of the values written so far
+    ///
+    /// See [`Hasher.finish`](../trait.Hasher.html#tymethod.finish).
+    fn finish(&self) -> Self::Output;
+
+    /// Writes some data into this hasher
+    fn write(&mut self, bytes: &[u8]);
+
+    /// Writes a single `u8` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `u16` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `u32` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `u64` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `u128` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `usize` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `i8` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `i16` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `i32` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `i64` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `i128` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Writes a single `isize` into this hasher
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Whether this hasher is insensitive to how its input is split across `write` calls
+    ///
+    /// See [`Hasher.is_split_insensitive`](../trait.Hasher.html#method.is_split_insensitive) for
+    /// the contract an implementation that returns `true` must uphold.
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        false
+    }
+}
+
+impl<H> ::Hasher for H
+where
+    H: HasherN<Output = u32>,
+{
+    #[inline]
+    fn finish(&self) -> u32 {
+        HasherN::finish(self)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        HasherN::write(self, bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        HasherN::write_u8(self, i)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        HasherN::write_u16(self, i)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        HasherN::write_u32(self, i)
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        HasherN::write_u64(self, i)
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        HasherN::write_u128(self, i)
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        HasherN::write_usize(self, i)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        HasherN::write_i8(self, i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        HasherN::write_i16(self, i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        HasherN::write_i32(self, i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        HasherN::write_i64(self, i)
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        HasherN::write_i128(self, i)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        HasherN::write_isize(self, i)
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        HasherN::is_split_insensitive(self)
+    }
+}
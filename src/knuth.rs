@@ -0,0 +1,99 @@
+// 2^32 / golden ratio, as suggested by Knuth in TAOCP vol. 3
+const K: u32 = 2654435761;
+
+/// Knuth's multiplicative hasher
+///
+/// Intended for fixed-width integer keys: the input is packed into little endian 32-bit words,
+/// each of which is XOR-ed into the state and multiplied by `2654435761`. `finish` XOR-folds the
+/// well mixed high half of the state into the low half. An incomplete trailing word is padded with
+/// zero bytes so, for example, `[1]` and `[1, 0, 0, 0]` hash to the same value.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, KnuthHasher};
+///
+/// let mut hasher = KnuthHasher::default();
+/// hasher.write(&[1, 0, 0, 0]);
+/// let hash = 2654435761u32;
+/// assert_eq!(hasher.finish(), hash ^ (hash >> 16));
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+    // incomplete word; its bytes are stored in little endian order
+    tail: u32,
+    // number of bytes in `tail`
+    count: u32,
+}
+
+impl Hasher {
+    fn push(&mut self, byte: u8) {
+        self.tail |= u32::from(byte) << (self.count * 8);
+        self.count += 1;
+
+        if self.count == 4 {
+            self.state = mix(self.state, self.tail);
+            self.tail = 0;
+            self.count = 0;
+        }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        let state = if self.count == 0 {
+            self.state
+        } else {
+            mix(self.state, self.tail)
+        };
+
+        state ^ (state >> 16)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.push(*byte);
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+fn mix(state: u32, word: u32) -> u32 {
+    (state ^ word).wrapping_mul(K)
+}
+
+#[cfg(test)]
+mod tests {
+    use {Hasher, KnuthHasher};
+
+    const KEYS: u32 = 4096;
+    const BUCKETS: usize = 1024;
+
+    // sequential keys, the typical worst case for a weak integer hash, spread over the buckets
+    #[test]
+    fn sequential_keys_distribution() {
+        let mut buckets = [0u32; BUCKETS];
+        for key in 0..KEYS {
+            let mut hasher = KnuthHasher::default();
+            hasher.write_u32(key);
+            buckets[hasher.finish() as usize % BUCKETS] += 1;
+        }
+
+        let fair_share = KEYS / BUCKETS as u32;
+        let occupied = buckets.iter().filter(|&&n| n != 0).count();
+        let max = buckets.iter().cloned().max().unwrap();
+
+        // at least 95% of the buckets are used and none gets more than three times its share
+        assert!(occupied >= BUCKETS * 95 / 100, "occupied = {}", occupied);
+        assert!(max <= 3 * fair_share, "max = {}", max);
+    }
+}
@@ -17,7 +17,8 @@
 //!
 //! # `#[derive(Hash32)]`
 //!
-//! The easiest way to implement `hash32::Hash` for a `struct` is to use the `#[derive(Hash32)]`.
+//! The easiest way to implement `hash32::Hash` for a `struct` or an `enum` is to use the
+//! `#[derive(Hash32)]`.
 //!
 //! ```
 //! #[macro_use]
@@ -29,12 +30,381 @@
 //! # fn main() {}
 //! ```
 //!
+//! The fields of a `struct` are hashed in declaration order. For an `enum` the discriminant of the
+//! active variant is hashed, as a `u32`, followed by the fields of that variant. Without explicit
+//! discriminants that is the index of the variant; with them, `A = 5` hashes as `5u32`, like
+//! `A as u32`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! enum Message {
+//!     Ping,
+//!     Ack(u16),
+//!     Data { id: u16, payload: [u8; 4] },
+//! }
+//!
+//! # fn main() {
+//! let mut derived = FnvHasher::default();
+//! Message::Ack(7).hash(&mut derived);
+//!
+//! let mut manual = FnvHasher::default();
+//! 1u32.hash(&mut manual);
+//! 7u16.hash(&mut manual);
+//!
+//! assert_eq!(derived.finish(), manual.finish());
+//! # }
+//! ```
+//!
+//! Apart from the discriminant of an `enum` the derive adds no framing: no field count, length
+//! prefix or separator. A tuple `struct` feeds the hasher exactly the same bytes as a hand-written
+//! implementation that hashes `self.0`, then `self.1`, and so on.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! struct Derived(u8, u16);
+//!
+//! struct Manual(u8, u16);
+//!
+//! impl Hash for Manual {
+//!     fn hash<H: Hasher>(&self, state: &mut H) {
+//!         self.0.hash(state);
+//!         self.1.hash(state);
+//!     }
+//! }
+//!
+//! // records the bytes fed to it
+//! #[derive(Default)]
+//! struct Recorder(Vec<u8>);
+//!
+//! impl Hasher for Recorder {
+//!     fn write(&mut self, bytes: &[u8]) {
+//!         self.0.extend_from_slice(bytes);
+//!     }
+//!
+//!     fn finish(&self) -> u32 {
+//!         0
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let mut derived = Recorder::default();
+//! Derived(1, 0x0302).hash(&mut derived);
+//!
+//! let mut manual = Recorder::default();
+//! Manual(1, 0x0302).hash(&mut manual);
+//!
+//! assert_eq!(derived.0, [1, 2, 3]);
+//! assert_eq!(derived.0, manual.0);
+//! # }
+//! ```
+//!
+//! In particular a newtype hashes exactly like the value it wraps, so wrapping e.g. a tick
+//! counter in its own type costs nothing.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! fn hash<T: Hash>(x: &T) -> u32 {
+//!     let mut hasher = FnvHasher::default();
+//!     x.hash(&mut hasher);
+//!     hasher.finish()
+//! }
+//!
+//! #[derive(Hash32)]
+//! struct Ticks(u32);
+//!
+//! # fn main() {
+//! for &t in &[0, 1, 1_000, u32::MAX] {
+//!     assert_eq!(hash(&Ticks(t)), hash(&t));
+//! }
+//! # }
+//! ```
+//!
+//! Fields marked with `#[hash32(skip)]` don't participate in the hash; the remaining fields are
+//! still hashed in declaration order.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! struct Packet {
+//!     id: u16,
+//!     #[hash32(skip)]
+//!     checksum: u32,
+//!     payload: [u8; 4],
+//! }
+//!
+//! # fn main() {
+//! fn hash<T: Hash>(x: &T) -> u32 {
+//!     let mut hasher = FnvHasher::default();
+//!     x.hash(&mut hasher);
+//!     hasher.finish()
+//! }
+//!
+//! let a = Packet { id: 1, checksum: 0, payload: [0, 1, 2, 3] };
+//! let b = Packet { id: 1, checksum: 0xdeadbeef, payload: [0, 1, 2, 3] };
+//! assert_eq!(hash(&a), hash(&b));
+//!
+//! let mut manual = FnvHasher::default();
+//! 1u16.hash(&mut manual);
+//! [0u8, 1, 2, 3].hash(&mut manual);
+//! assert_eq!(hash(&a), manual.finish());
+//! # }
+//! ```
+//!
+//! `#[hash32(order = N)]` changes the position of a field in the hash, e.g. to match a hash
+//! computed by another system. The fields with an `order` are hashed first, sorted by it, followed
+//! by the remaining fields in declaration order.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! struct Header {
+//!     kind: u8,
+//!     len: u16,
+//!     flags: u8,
+//! }
+//!
+//! #[derive(Hash32)]
+//! struct Reordered {
+//!     #[hash32(order = 1)]
+//!     len: u16,
+//!     flags: u8,
+//!     #[hash32(order = 0)]
+//!     kind: u8,
+//! }
+//!
+//! # fn main() {
+//! fn hash<T: Hash>(x: &T) -> u32 {
+//!     let mut hasher = FnvHasher::default();
+//!     x.hash(&mut hasher);
+//!     hasher.finish()
+//! }
+//!
+//! assert_eq!(
+//!     hash(&Header { kind: 1, len: 2, flags: 3 }),
+//!     hash(&Reordered { len: 2, flags: 3, kind: 1 })
+//! );
+//! # }
+//! ```
+//!
+//! `#[hash32(with = "path")]` hashes the value returned by `path(&field)` instead of the field
+//! itself, which is useful to normalize a field before hashing it. The function can return any
+//! type that implements `hash32::Hash`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! fn low_byte(x: &u16) -> u8 {
+//!     *x as u8
+//! }
+//!
+//! #[derive(Hash32)]
+//! struct Register {
+//!     addr: u8,
+//!     // the high byte is reserved
+//!     #[hash32(with = "low_byte")]
+//!     value: u16,
+//! }
+//!
+//! # fn main() {
+//! fn hash<T: Hash>(x: &T) -> u32 {
+//!     let mut hasher = FnvHasher::default();
+//!     x.hash(&mut hasher);
+//!     hasher.finish()
+//! }
+//!
+//! let a = Register { addr: 1, value: 0x0042 };
+//! let b = Register { addr: 1, value: 0xff42 };
+//! assert_eq!(hash(&a), hash(&b));
+//!
+//! let mut manual = FnvHasher::default();
+//! 1u8.hash(&mut manual);
+//! 0x42u8.hash(&mut manual);
+//! assert_eq!(hash(&a), manual.finish());
+//! # }
+//! ```
+//!
+//! For an `enum` whose variants have no fields, `#[hash32(const)]` also generates a `const fn
+//! const_hash(&self) -> u32` that returns the same hash as the derived `Hash` implementation
+//! with a default [`FnvHasher`](type.FnvHasher.html), so it can be computed in `const` contexts.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Clone, Copy, Hash32)]
+//! #[hash32(const)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//!     Blue,
+//! }
+//!
+//! const GREEN: u32 = Color::Green.const_hash();
+//!
+//! # fn main() {
+//! for color in &[Color::Red, Color::Green, Color::Blue] {
+//!     let mut hasher = FnvHasher::default();
+//!     color.hash(&mut hasher);
+//!     assert_eq!(color.const_hash(), hasher.finish());
+//! }
+//!
+//! let mut hasher = FnvHasher::default();
+//! Color::Green.hash(&mut hasher);
+//! match hasher.finish() {
+//!     GREEN => {}
+//!     _ => unreachable!(),
+//! }
+//! # }
+//! ```
+//!
+//! Every type parameter of a generic type gets a `hash32::Hash` bound in the generated `impl`;
+//! lifetimes, existing bounds and `where` clauses are preserved.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! struct Pair<A, B>(A, B);
+//!
+//! #[derive(Hash32)]
+//! struct Entry<'a, K: Copy + 'a, V>
+//! where
+//!     V: Clone,
+//! {
+//!     key: &'a K,
+//!     value: V,
+//! }
+//!
+//! # fn main() {
+//! fn hash<T: Hash>(x: &T) -> u32 {
+//!     let mut hasher = FnvHasher::default();
+//!     x.hash(&mut hasher);
+//!     hasher.finish()
+//! }
+//!
+//! assert_eq!(hash(&Pair(1u8, 2u16)), hash(&Entry { key: &1u8, value: 2u16 }));
+//! # }
+//! ```
+//!
+//! When those bounds are wrong, e.g. because a type parameter only appears in a `PhantomData`,
+//! `#[hash32(bound = "..")]` replaces them with the given `where` clause predicates.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate hash32_derive;
+//! extern crate hash32;
+//!
+//! use std::marker::PhantomData;
+//!
+//! use hash32::{FnvHasher, Hash, Hasher};
+//!
+//! #[derive(Hash32)]
+//! #[hash32(bound = "K: hash32::Hash")]
+//! struct Handle<K, T> {
+//!     key: K,
+//!     kind: PhantomData<T>,
+//! }
+//!
+//! // doesn't implement `Hash`
+//! struct Texture;
+//!
+//! # fn main() {
+//! let handle = Handle::<u16, Texture> {
+//!     key: 7,
+//!     kind: PhantomData,
+//! };
+//!
+//! let mut derived = FnvHasher::default();
+//! handle.hash(&mut derived);
+//!
+//! let mut manual = FnvHasher::default();
+//! 7u16.hash(&mut manual);
+//!
+//! assert_eq!(derived.finish(), manual.finish());
+//! # }
+//! ```
+//!
 //! # Hashers
 //!
 //! This crate provides implementations of the following 32-bit hashing algorithms:
 //!
-//! - [Fowler-Noll-Vo](struct.FnvHasher.html)
+//! - [Adler-32](struct.Adler32Hasher.html)
+//! - [CityHash32](struct.CityHash32Hasher.html)
+//! - [CRC-32](struct.Crc32Hasher.html)
+//! - [CRC-32C](struct.Crc32cHasher.html)
+//! - [djb2](struct.Djb2Hasher.html)
+//! - [ELF](struct.ElfHasher.html)
+//! - [Fletcher-32](struct.Fletcher32Hasher.html)
+//! - [Fowler-Noll-Vo](type.FnvHasher.html) (FNV-1a) and [FNV-1](type.Fnv1Hasher.html), both
+//!   aliases of the variant generic [`Fnv`](struct.Fnv.html), and a
+//!   [salted](struct.SaltedFnvHasher.html) FNV-1a
+//! - [FxHash](struct.FxHasher32.html)
+//! - [Jenkins one-at-a-time](struct.JenkinsOaatHasher.html)
+//! - [Knuth multiplicative](struct.KnuthHasher.html)
+//! - [lookup3](struct.Lookup3Hasher.html)
+//! - [MurmurHash2](struct.Murmur2Hasher.html) and [MurmurHash2A](struct.Murmur2aHasher.html)
 //! - [MurmurHash3](struct.Murmur3Hasher.html)
+//! - [Pearson](struct.PearsonHasher.html)
+//! - [sdbm](struct.SdbmHasher.html)
+//! - [SuperFastHash](struct.SuperFastHasher.html)
+//! - [Thomas Wang's integer hash](struct.WangHasher.html)
+//! - [wyhash](struct.WyHash32Hasher.html)
+//! - [xxHash](struct.XxHash32.html)
+//!
+//! It also provides a [Rabin-Karp](struct.RabinKarp.html) rolling hash and the
+//! [XOR](struct.XorSumHasher.html) and [additive](struct.AddSumHasher.html) checksums.
+//!
+//! # Cargo features
+//!
+//! - `alloc`: implements `Hash` for `Arc`, `Box`, `Rc`, `String` and `Vec`. Requires a global
+//!   allocator.
+//! - `const-fn`: no longer has any effect; `BuildHasherDefault::new` is a `const fn` on stable.
+//! - `core-hash-bridge`: provides [`CoreHasherAdapter`](struct.CoreHasherAdapter.html), which
+//!   implements `core::hash::Hasher` on top of a 32-bit `Hasher`, and the
+//!   [`hash32_of`](fn.hash32_of.html) helper. Also implements `core::hash::BuildHasher` for
+//!   `BuildHasherDefault`.
+//! - `std`: enables `core-hash-bridge` and provides the [`Hash32Map`](type.Hash32Map.html) and
+//!   [`Hash32Set`](type.Hash32Set.html) aliases of the `std` collections.
+//! - `test`: provides [`RecordingHasher`](struct.RecordingHasher.html), which records the bytes
+//!   it is fed so tests can check the exact encoding of a `Hash` implementation.
 //!
 //! # Future
 //!
@@ -55,22 +425,161 @@
 //!
 //! With this change a single `#[derive(Hash)]` would enough to make a type hashable with 32-bit and
 //! 64-bit hashers.
+//!
+//! The [`generic`](generic/index.html) module provides a preview of that interface.
 
 #![deny(missing_docs)]
 #![deny(warnings)]
-#![cfg_attr(feature = "const-fn", feature(const_fn))]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate byteorder;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, LE};
+use core::cell::Cell;
+use core::cmp::{Ordering, Reverse};
+use core::ffi::CStr;
 use core::marker::PhantomData;
-use core::{mem, slice};
+use core::mem::{self, ManuallyDrop};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use core::ops::{
+    Bound, ControlFlow, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use core::ptr::NonNull;
+use core::sync::atomic;
+use core::time::Duration;
 
+pub use adler32::Hasher as Adler32Hasher;
+pub use c_buf::CBuf;
+pub use checksum::AddHasher as AddSumHasher;
+pub use checksum::XorHasher as XorSumHasher;
+pub use city::Hasher as CityHash32Hasher;
+pub use city::CAPACITY as CITY_HASH32_CAPACITY;
+#[cfg(feature = "core-hash-bridge")]
+pub use core_hash::{hash32_of, CoreHasherAdapter};
+pub use crc32::Hasher as Crc32Hasher;
+pub use crc32c::Hasher as Crc32cHasher;
+pub use djb2::AddHasher as Djb2AddHasher;
+pub use djb2::Hasher as Djb2Hasher;
+pub use double::{double_hash, DoubleHasher};
+pub use elf::Hasher as ElfHasher;
+pub use fletcher::Hasher as Fletcher32Hasher;
+pub use fmt_writer::HashFmtWriter;
 pub use fnv::Hasher as FnvHasher;
+pub use fnv::SaltedHasher as SaltedFnvHasher;
+pub use fnv::{Fnv, Fnv1, Fnv1Hasher, Fnv1a, FnvVariant};
+pub use fnv::{FNV_OFFSET_BASIS_32, FNV_PRIME_32};
+pub use fx::Hasher as FxHasher32;
+pub use knuth::Hasher as KnuthHasher;
+pub use lookup3::Hasher as Lookup3Hasher;
+pub use murmur2::Hasher as Murmur2Hasher;
+pub use murmur2::Hasher2a as Murmur2aHasher;
 pub use murmur3::Hasher as Murmur3Hasher;
+pub use one_at_a_time::Hasher as JenkinsOaatHasher;
+pub use pair::Pair;
+pub use pearson::Hasher as PearsonHasher;
+pub use rabin_karp::RabinKarp;
+pub use raw_str::RawStr;
+#[cfg(feature = "test")]
+pub use recording::Hasher as RecordingHasher;
+#[cfg(feature = "test")]
+pub use recording::CAPACITY as RECORDING_HASHER_CAPACITY;
+pub use sdbm::Hasher as SdbmHasher;
+pub use super_fast::Hasher as SuperFastHasher;
+pub use unordered::UnorderedCombine;
+pub use unprefixed::Unprefixed;
+pub use wang::Hasher as WangHasher;
+pub use wyhash32::Hasher as WyHash32Hasher;
+pub use xxhash32::Hasher as XxHash32;
 
+pub mod generic;
+
+mod adler32;
+mod c_buf;
+mod checksum;
+mod city;
+#[cfg(feature = "core-hash-bridge")]
+mod core_hash;
+mod crc32;
+mod crc32c;
+mod djb2;
+mod double;
+mod elf;
+mod fletcher;
+mod fmt_writer;
 mod fnv;
+mod fx;
+mod knuth;
+mod lookup3;
+mod murmur2;
 mod murmur3;
+mod one_at_a_time;
+mod pair;
+mod pearson;
+mod rabin_karp;
+mod raw_str;
+#[cfg(feature = "test")]
+mod recording;
+mod sdbm;
+mod super_fast;
+mod unordered;
+mod unprefixed;
+mod wang;
+mod wyhash32;
+mod xxhash32;
+
+/// A `std::collections::HashMap` that hashes its keys with [`FnvHasher`](type.FnvHasher.html)
+///
+/// The keys must implement `core::hash::Hash`, which is what `HashMap` uses.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::Hash32Map;
+///
+/// let mut map = Hash32Map::default();
+/// map.insert(1u8, "one");
+/// map.insert(2, "two");
+///
+/// assert_eq!(map.get(&1), Some(&"one"));
+/// assert_eq!(map.get(&3), None);
+/// ```
+#[cfg(feature = "std")]
+pub type Hash32Map<K, V> = std::collections::HashMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+/// A `std::collections::HashSet` that hashes its values with [`FnvHasher`](type.FnvHasher.html)
+///
+/// # Examples
+///
+/// ```
+/// use hash32::Hash32Set;
+///
+/// let mut set = Hash32Set::default();
+/// assert!(set.insert("foo"));
+/// assert!(!set.insert("foo"));
+///
+/// assert!(set.contains("foo"));
+/// assert!(!set.contains("bar"));
+/// ```
+#[cfg(feature = "std")]
+pub type Hash32Set<T> = std::collections::HashSet<T, BuildHasherDefault<FnvHasher>>;
 
 /// See [`core::hash::BuildHasherDefault`][0] for details
 ///
@@ -98,7 +607,16 @@ where
     H: Default + Hasher,
 {
     /// `const` constructor
-    #[cfg(feature = "const-fn")]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{BuildHasher, BuildHasherDefault, FnvHasher};
+    ///
+    /// static BUILDER: BuildHasherDefault<FnvHasher> = BuildHasherDefault::new();
+    ///
+    /// assert_eq!(BUILDER.hash_one("foo"), hash32::fnv_build_hasher().hash_one("foo"));
+    /// ```
     pub const fn new() -> Self {
         BuildHasherDefault {
             _marker: PhantomData,
@@ -117,6 +635,60 @@ where
     }
 }
 
+/// A `BuildHasher` that creates hashers from a fixed seed
+///
+/// Every hasher built by a `BuildHasherSeeded` starts from the same seed so hashing is
+/// reproducible, but maps using different seeds won't share the same collision patterns.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{BuildHasher, BuildHasherSeeded, Hash, Hasher, Murmur3Hasher};
+///
+/// fn hash<T: Hash>(builder: &BuildHasherSeeded<Murmur3Hasher>, x: T) -> u32 {
+///     let mut hasher = builder.build_hasher();
+///     x.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let a = BuildHasherSeeded::new(0xdead_beef);
+/// let b = BuildHasherSeeded::new(0xcafe_babe);
+///
+/// assert_eq!(hash(&a, "key"), hash(&a, "key"));
+/// assert_ne!(hash(&a, "key"), hash(&b, "key"));
+/// ```
+pub struct BuildHasherSeeded<H>
+where
+    H: SeededHasher,
+{
+    seed: u32,
+    _marker: PhantomData<H>,
+}
+
+impl<H> BuildHasherSeeded<H>
+where
+    H: SeededHasher,
+{
+    /// Creates a builder whose hashers start from the given `seed`
+    pub const fn new(seed: u32) -> Self {
+        BuildHasherSeeded {
+            seed,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<H> BuildHasher for BuildHasherSeeded<H>
+where
+    H: SeededHasher,
+{
+    type Hasher = H;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        H::with_seed(self.seed)
+    }
+}
+
 /// See [`core::hash::BuildHasher`][0] for details
 ///
 /// [0]: https://doc.rust-lang.org/core/hash/trait.BuildHasher.html
@@ -130,6 +702,32 @@ pub trait BuildHasher {
     ///
     /// [0]: https://doc.rust-lang.org/std/hash/trait.BuildHasher.html#tymethod.build_hasher
     fn build_hasher(&self) -> Self::Hasher;
+
+    /// See [`core::hash::BuildHasher.hash_one`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.BuildHasher.html#method.hash_one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{BuildHasher, BuildHasherDefault, FnvHasher, Hash, Hasher};
+    ///
+    /// let builder = BuildHasherDefault::<FnvHasher>::default();
+    ///
+    /// let mut hasher = builder.build_hasher();
+    /// "key".hash(&mut hasher);
+    ///
+    /// assert_eq!(builder.hash_one("key"), hasher.finish());
+    /// ```
+    fn hash_one<T>(&self, x: T) -> u32
+    where
+        T: Hash,
+        Self: Sized,
+    {
+        let mut hasher = self.build_hasher();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// See [`core::hash::Hasher`][0] for details
@@ -140,101 +738,1863 @@ pub trait BuildHasher {
 ///
 /// Implementers of this trait must *not* perform any 64-bit (or 128-bit) operation while computing
 /// the hash.
+///
+/// # Implementing
+///
+/// `Hasher` is implemented for every [`HasherN<Output = u32>`](generic/trait.HasherN.html), which
+/// is how the hashers of this crate implement it. A new hasher can do the same, and then also work
+/// with code written against `HasherN`, or implement `Hasher` directly.
 pub trait Hasher {
     /// See [`core::hash::Hasher.finish`][0]
     ///
     /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#tymethod.finish
+    ///
+    /// `finish` must not change the state of the hasher: it can be called any number of times,
+    /// always returning the hash of everything written so far, and more data can be written
+    /// afterwards. Any finalization, like the `fmix32` step of MurmurHash3, is applied to a copy
+    /// of the state. All the hashers of this crate uphold this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher, Murmur3Hasher, XxHash32};
+    ///
+    /// fn check<H: Default + Hasher>() {
+    ///     let log: &[&[u8]] = &[b"boot", b"", b"link up", b"dhcp lease acquired"];
+    ///
+    ///     let mut running = H::default();
+    ///     let mut all = Vec::new();
+    ///     for entry in log {
+    ///         running.write(entry);
+    ///         all.extend_from_slice(entry);
+    ///
+    ///         let mut oneshot = H::default();
+    ///         oneshot.write(&all);
+    ///         assert_eq!(running.finish(), oneshot.finish());
+    ///         assert_eq!(running.finish(), running.finish());
+    ///     }
+    /// }
+    ///
+    /// check::<FnvHasher>();
+    /// check::<Murmur3Hasher>();
+    /// check::<XxHash32>();
+    /// ```
     fn finish(&self) -> u32;
 
+    /// Returns the hash zero extended to a `u64`
+    ///
+    /// This is meant for APIs that expect a 64-bit hash; the high 32 bits are always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let mut hasher = FnvHasher::default();
+    /// hasher.write(b"foobar");
+    ///
+    /// assert_eq!(hasher.finish(), 0xbf9cf968);
+    /// assert_eq!(hasher.finish_u64(), 0x0000_0000_bf9c_f968);
+    /// ```
+    #[inline]
+    fn finish_u64(&self) -> u64 {
+        u64::from(self.finish())
+    }
+
+    /// Returns the hash folded into 16 bits
+    ///
+    /// The high half of the 32-bit hash is XOR-ed into the low half, `(h ^ (h >> 16)) as u16`, so
+    /// every bit of the hash affects the result. This is better than truncating the hash with `as
+    /// u16` for hashers that don't mix their input into the low bits of the state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FxHasher32, Hasher};
+    ///
+    /// let mut folded = [false; 1 << 16];
+    /// let mut truncated = [false; 1 << 16];
+    /// for i in 0..1024u32 {
+    ///     let mut hasher = FxHasher32::default();
+    ///     hasher.write_u32(i << 16);
+    ///
+    ///     assert_eq!(hasher.finish16(), (hasher.finish() ^ (hasher.finish() >> 16)) as u16);
+    ///
+    ///     folded[usize::from(hasher.finish16())] = true;
+    ///     truncated[usize::from(hasher.finish() as u16)] = true;
+    /// }
+    ///
+    /// // distinct values
+    /// assert_eq!(folded.iter().filter(|x| **x).count(), 1024);
+    /// assert_eq!(truncated.iter().filter(|x| **x).count(), 1);
+    /// ```
+    #[inline]
+    fn finish16(&self) -> u16 {
+        let hash = self.finish();
+        (hash ^ (hash >> 16)) as u16
+    }
+
     /// See [`core::hash::Hasher.write`][0]
     ///
     /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#tymethod.write
     fn write(&mut self, bytes: &[u8]);
-}
-
-/// See [`core::hash::Hash`][0] for details
-///
-/// [0]: https://doc.rust-lang.org/core/hash/trait.Hash.html
-pub trait Hash {
-    /// Feeds this value into the given `Hasher`.
-    fn hash<H>(&self, state: &mut H)
-    where
-        H: Hasher;
 
-    /// Feeds a slice of this type into the given `Hasher`.
-    fn hash_slice<H>(data: &[Self], state: &mut H)
-    where
-        H: Hasher,
-        Self: Sized,
-    {
-        for piece in data {
-            piece.hash(state);
-        }
+    /// See [`core::hash::Hasher.write_u8`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_u8
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write(&i.to_le_bytes())
     }
-}
 
-macro_rules! int {
-    ($ty:ident) => {
-        impl Hash for $ty {
-            fn hash<H>(&self, state: &mut H)
-            where
-                H: Hasher,
-            {
-                unsafe { state.write(&mem::transmute::<$ty, [u8; mem::size_of::<$ty>()]>(*self)) }
-            }
+    /// See [`core::hash::Hasher.write_u16`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_u16
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
 
-            fn hash_slice<H>(data: &[Self], state: &mut H)
-            where
-                H: Hasher,
-            {
-                let newlen = data.len() * mem::size_of::<$ty>();
-                let ptr = data.as_ptr() as *const u8;
-                unsafe { state.write(slice::from_raw_parts(ptr, newlen)) }
-            }
+    /// See [`core::hash::Hasher.write_u32`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_u32
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let mut a = FnvHasher::default();
+    /// a.write_u32(0xdead_beef);
+    ///
+    /// let mut b = FnvHasher::default();
+    /// b.write(&[0xef, 0xbe, 0xad, 0xde]);
+    ///
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_u64`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_u64
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_u128`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_u128
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_usize`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_usize
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_i8`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_i8
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_i16`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_i16
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_i32`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_i32
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_i64`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_i64
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_i128`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_i128
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// See [`core::hash::Hasher.write_isize`][0]
+    ///
+    /// [0]: https://doc.rust-lang.org/std/hash/trait.Hasher.html#method.write_isize
+    ///
+    /// The default implementation feeds the little endian bytes of `i` to `write`.
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write(&i.to_le_bytes())
+    }
+
+    /// Mixes a previously computed `hash` into the state of this hasher
+    ///
+    /// This is meant for hierarchical hashing: the components of a key are hashed independently
+    /// and their hashes are then combined into the final hash. The default implementation feeds
+    /// `hash` to `write_u32`, i.e. it goes through the regular mixing step of the hasher, so the
+    /// order in which hashes are combined matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let a = hash32::fnv1a(b"foo");
+    /// let b = hash32::fnv1a(b"bar");
+    ///
+    /// let mut ab = FnvHasher::default();
+    /// ab.combine(a);
+    /// ab.combine(b);
+    ///
+    /// let mut ba = FnvHasher::default();
+    /// ba.combine(b);
+    /// ba.combine(a);
+    ///
+    /// assert_ne!(ab.finish(), ba.finish());
+    ///
+    /// let mut expected = FnvHasher::default();
+    /// expected.write_u32(a);
+    /// expected.write_u32(b);
+    /// assert_eq!(ab.finish(), expected.finish());
+    /// ```
+    #[inline]
+    fn combine(&mut self, hash: u32) {
+        self.write_u32(hash)
+    }
+
+    /// Returns a copy of this hasher, i.e. of everything it has been fed so far
+    ///
+    /// This is the same as `clone`; forking the state after hashing a common prefix is cheaper
+    /// than hashing the prefix again for every key that starts with it. See also
+    /// [`hash_suffixes`](fn.hash_suffixes.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let mut prefix = FnvHasher::default();
+    /// prefix.write(b"/dev/");
+    ///
+    /// for name in &["null", "tty0", "zero"] {
+    ///     let mut fork = prefix.fork();
+    ///     fork.write(name.as_bytes());
+    ///
+    ///     let mut full = FnvHasher::default();
+    ///     full.write(format!("/dev/{}", name).as_bytes());
+    ///
+    ///     assert_eq!(fork.finish(), full.finish());
+    /// }
+    /// ```
+    #[inline]
+    fn fork(&self) -> Self
+    where
+        Self: Clone + Sized,
+    {
+        self.clone()
+    }
+
+    /// Returns this hasher to its `Default` state
+    ///
+    /// Note that a hasher created from a seed (see [`SeededHasher`]) goes back to the default seed.
+    ///
+    /// [`SeededHasher`]: trait.SeededHasher.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, Hasher};
+    ///
+    /// let mut hasher = FnvHasher::default();
+    /// for key in &["foo", "bar", "baz"] {
+    ///     hasher.write(key.as_bytes());
+    ///     let hash = hasher.finish();
+    ///     hasher.reset();
+    ///
+    ///     assert_eq!(hash, hash32::fnv1a(key.as_bytes()));
+    /// }
+    /// ```
+    #[inline]
+    fn reset(&mut self)
+    where
+        Self: Default + Sized,
+    {
+        *self = Self::default();
+    }
+
+    /// Whether this hasher is insensitive to how its input is split across `write` calls
+    ///
+    /// `hash_slice` of the integer types uses this to feed the elements to `write` many at a time,
+    /// instead of calling a `write_*` method for every element, which is faster. An
+    /// implementation must only return `true` if both of these hold:
+    ///
+    /// - feeding some bytes in one `write` call or split across several yields the same hash, and
+    /// - every `write_*` method is the default little endian encoding, i.e. `write_u32(i)` has the
+    ///   same effect as `write(&i.to_le_bytes())`, and likewise for the other integer types.
+    ///
+    /// A hasher that returns `true` without upholding this contract hashes integer slices
+    /// differently than their elements hashed one by one, and its hashes of slices change
+    /// depending on how they're grouped into `write` calls.
+    ///
+    /// The default implementation returns `false`, which is always correct. All the hashers of
+    /// this crate, except for [`FxHasher32`](struct.FxHasher32.html), return `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{FnvHasher, FxHasher32, Hasher};
+    ///
+    /// assert!(FnvHasher::default().is_split_insensitive());
+    ///
+    /// // FxHash mixes in every `write` separately
+    /// assert!(!FxHasher32::default().is_split_insensitive());
+    /// ```
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        false
+    }
+}
+
+/// A `Hasher` that can start from a 32-bit seed
+pub trait SeededHasher: Hasher {
+    /// Creates a hasher that starts from the given `seed`
+    fn with_seed(seed: u32) -> Self;
+}
+
+/// A `Hasher` that keeps track of how many bytes it has been fed
+///
+/// This is implemented by the hashers that need the length of the input anyway, plus
+/// [`FnvHasher`](type.FnvHasher.html) and [`Fnv1Hasher`](type.Fnv1Hasher.html). Hashers that
+/// keep a 32-bit length, like the one mixed into the MurmurHash3 hash, wrap around after
+/// 2<sup>32</sup> bytes.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{CountingHasher, FnvHasher, Hasher, Murmur3Hasher, XxHash32};
+///
+/// fn check<H: CountingHasher + Default>() {
+///     let bytes = b"The quick brown fox jumps over the lazy dog";
+///
+///     let mut hasher = H::default();
+///     assert_eq!(hasher.bytes_written(), 0);
+///
+///     hasher.write(&bytes[..10]);
+///     assert_eq!(hasher.bytes_written(), 10);
+///
+///     hasher.write(&bytes[10..]);
+///     hasher.write_u32(0);
+///     assert_eq!(hasher.bytes_written(), bytes.len() + 4);
+/// }
+///
+/// check::<FnvHasher>();
+/// check::<Murmur3Hasher>();
+/// check::<XxHash32>();
+/// ```
+pub trait CountingHasher: Hasher {
+    /// Returns the number of bytes written to this hasher so far
+    fn bytes_written(&self) -> usize;
+}
+
+/// See [`core::hash::Hash`][0] for details
+///
+/// [0]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+///
+/// # Portability
+///
+/// Integers are fed to the hasher through the `Hasher.write_*` methods, which by default encode
+/// them as little endian bytes, so the same value produces the same hash on little and big endian
+/// targets. Note that `usize` and `isize` still have the width of the target pointer.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// 0x0102_0304u32.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// b.write(&[0x04, 0x03, 0x02, 0x01]);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// A signed integer is fed as the little endian bytes of its two's complement representation, so
+/// it hashes like the unsigned integer of the same width with the same bit pattern, e.g. `-1i8`
+/// like `255u8`. Integers of different widths feed a different number of bytes and don't hash the
+/// same. This holds for every hasher that doesn't override `write_i*` with a different encoding;
+/// the hashers of this crate don't.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher, Murmur3Hasher};
+///
+/// fn hash<T: Hash, H: Default + Hasher>(x: T) -> u32 {
+///     let mut hasher = H::default();
+///     x.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// fn check<H: Default + Hasher>() {
+///     assert_eq!(hash::<_, H>(-1i8), hash::<_, H>(255u8));
+///     assert_eq!(hash::<_, H>(-2i16), hash::<_, H>(0xfffeu16));
+///     assert_eq!(hash::<_, H>(i32::MIN), hash::<_, H>(0x8000_0000u32));
+///     assert_eq!(hash::<_, H>(-1i64), hash::<_, H>(u64::MAX));
+///     assert_eq!(hash::<_, H>(-3i128), hash::<_, H>(-3i128 as u128));
+///     assert_eq!(hash::<_, H>(-1isize), hash::<_, H>(usize::MAX));
+///
+///     assert_ne!(hash::<_, H>(-1i8), hash::<_, H>(-1i16));
+///     assert_ne!(hash::<_, H>(255u8), hash::<_, H>(255u16));
+/// }
+///
+/// check::<FnvHasher>();
+/// check::<Murmur3Hasher>();
+/// ```
+///
+/// 128-bit integers are fed as their 16 little endian bytes, i.e. like their low and then their
+/// high 64-bit half, without any 128-bit arithmetic.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 0xfedc_ba98_7654_3210u64.hash(&mut b);
+/// 0x0123_4567_89ab_cdefu64.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// assert_eq!(a.finish(), 0xa4edab85);
+/// ```
+///
+/// Atomic integers hash like the value they hold at the time they are hashed.
+///
+/// ```
+/// use std::sync::atomic::AtomicU32;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// AtomicU32::new(42).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 42u32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// The `NonZero*` integers hash like the integer they wrap.
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// NonZeroU32::new(5).unwrap().hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 5u32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// Slices of integers are hashed element by element, after their length.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// [1u16, 2, 3][..].hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 3usize.hash(&mut b);
+/// 1u16.hash(&mut b);
+/// 2u16.hash(&mut b);
+/// 3u16.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// `hash_slice` never reinterprets the memory of the slice: the elements are fed as little endian
+/// bytes, so the hash is the same on little and big endian targets. Every element goes through
+/// its `write_*` method, except when the hasher
+/// [is split insensitive](trait.Hasher.html#method.is_split_insensitive): then the encoded
+/// elements of a slice of integers are fed to `write` 64 bytes at a time, which gives the same
+/// hash.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let data = [0x0102_0304u32, 0x0506_0708, 0xdead_beef];
+///
+/// let mut a = FnvHasher::default();
+/// u32::hash_slice(&data, &mut a);
+///
+/// let mut b = FnvHasher::default();
+/// for x in data.iter() {
+///     x.hash(&mut b);
+/// }
+///
+/// let mut c = FnvHasher::default();
+/// for x in data.iter() {
+///     c.write(&x.to_le_bytes());
+/// }
+///
+/// assert_eq!(a.finish(), b.finish());
+/// assert_eq!(a.finish(), c.finish());
+///
+/// let mut a = FnvHasher::default();
+/// i64::hash_slice(&[-1, 2], &mut a);
+///
+/// let mut b = FnvHasher::default();
+/// b.write(&(-1i64).to_le_bytes());
+/// b.write(&2i64.to_le_bytes());
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// The batched `write` calls of a split insensitive hasher give the same hash as feeding the
+/// elements one at a time.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher, Murmur3Hasher, XxHash32};
+///
+/// fn check<H: Default + Hasher>() {
+///     let data: Vec<u16> = (0..37).map(|i| i * 0x0123).collect();
+///     for len in 0..data.len() {
+///         let data = &data[..len];
+///
+///         // start unaligned so that buffering hashers have a partial block
+///         let mut bulk = H::default();
+///         bulk.write_u8(1);
+///         u16::hash_slice(data, &mut bulk);
+///
+///         let mut one_by_one = H::default();
+///         one_by_one.write_u8(1);
+///         for x in data {
+///             one_by_one.write_u16(*x);
+///         }
+///
+///         assert_eq!(bulk.finish(), one_by_one.finish());
+///     }
+/// }
+///
+/// check::<FnvHasher>();
+/// check::<Murmur3Hasher>();
+/// check::<XxHash32>();
+/// ```
+///
+/// The elements of a tuple are hashed in order, so `(a, b)` and `(b, a)` hash differently.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// (1u16, 2u8).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (2u16, 1u8).hash(&mut b);
+///
+/// assert_ne!(a.finish(), b.finish());
+/// ```
+///
+/// Tuples compose with references, and the last element may be unsized.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// (&"key", 3u8).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// ("key", 3u8).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+pub trait Hash {
+    /// Feeds this value into the given `Hasher`.
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher;
+
+    /// Feeds a slice of this type into the given `Hasher`.
+    fn hash_slice<H>(data: &[Self], state: &mut H)
+    where
+        H: Hasher,
+        Self: Sized,
+    {
+        for piece in data {
+            piece.hash(state);
+        }
+    }
+}
+
+/// Computes the 32-bit FNV-1a hash of `bytes`
+///
+/// This is equivalent to feeding `bytes` to a [`FnvHasher`](type.FnvHasher.html) and, being a
+/// `const fn`, it can be used to compute hashes at compile time. This works on stable and doesn't
+/// need the `const-fn` feature.
+///
+/// ```
+/// use hash32::{FnvHasher, Hasher};
+///
+/// const HASH: u32 = hash32::fnv1a(b"foobar");
+///
+/// let mut hasher = FnvHasher::default();
+/// hasher.write(b"foobar");
+///
+/// assert_eq!(HASH, hasher.finish());
+/// ```
+///
+/// Precomputed hashes can be used as `match` patterns.
+///
+/// ```
+/// const GET: u32 = hash32::fnv1a(b"GET");
+/// const PUT: u32 = hash32::fnv1a(b"PUT");
+///
+/// fn method(name: &[u8]) -> Option<u8> {
+///     match hash32::fnv1a(name) {
+///         GET => Some(0),
+///         PUT => Some(1),
+///         _ => None,
+///     }
+/// }
+///
+/// assert_eq!(method(b"GET"), Some(0));
+/// assert_eq!(method(b"PUT"), Some(1));
+/// assert_eq!(method(b"DELETE"), None);
+/// ```
+pub const fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut state = FNV_OFFSET_BASIS_32;
+    let mut i = 0;
+    while i < bytes.len() {
+        state ^= bytes[i] as u32;
+        state = state.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    state
+}
+
+/// Computes the 32-bit MurmurHash3 hash of `bytes` using the given `seed`
+///
+/// This is equivalent to feeding `bytes` to a [`Murmur3Hasher`](struct.Murmur3Hasher.html) that
+/// starts from `seed`.
+///
+/// ```
+/// use hash32::{Hasher, Murmur3Hasher};
+///
+/// let mut hasher = Murmur3Hasher::with_seed(42);
+/// hasher.write(b"foobar");
+///
+/// assert_eq!(hash32::murmur3(b"foobar", 42), hasher.finish());
+/// ```
+pub fn murmur3(bytes: &[u8], seed: u32) -> u32 {
+    let mut hasher = Murmur3Hasher::with_seed(seed);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Derives a 32-bit seed for a [`SeededHasher`](trait.SeededHasher.html) from a byte string key
+///
+/// The seed is the 32-bit FNV-1a hash of `key`: starting from the offset basis `0x811c9dc5`, each
+/// byte is XORed into the state, which is then multiplied by the prime `0x01000193`, wrapping on
+/// overflow. The derivation is stable across targets and releases, so the same key can be turned
+/// into the same seed off-device, e.g. with this Python:
+///
+/// ``` text
+/// h = 0x811c9dc5
+/// for b in key:
+///     h = ((h ^ b) * 0x01000193) & 0xffffffff
+/// ```
+///
+/// The seed is not a secret derived in the cryptographic sense: anyone who knows the key can
+/// compute it, and a seed doesn't make any of the hashers in this crate resistant to HashDoS.
+///
+/// ```
+/// use hash32::{Hasher, Murmur3Hasher, SeededHasher};
+///
+/// const SEED: u32 = hash32::derive_seed(b"device-secret");
+/// assert_eq!(SEED, 0x96253eca);
+///
+/// let mut hasher = Murmur3Hasher::with_seed(SEED);
+/// hasher.write(b"foobar");
+/// assert_eq!(hasher.finish(), hash32::murmur3(b"foobar", 0x96253eca));
+/// ```
+pub const fn derive_seed(key: &[u8]) -> u32 {
+    fnv1a(key)
+}
+
+/// Returns the hashes of `prefix` followed by each one of the `suffixes`
+///
+/// `prefix` is a hasher that has already been fed the common prefix; it's [forked] for every
+/// suffix so the prefix is only hashed once. The suffixes are fed to `write` as they are, so each
+/// hash equals the one of writing the prefix and the suffix in one go, as long as the hasher is
+/// not sensitive to how its input is split.
+///
+/// [forked]: trait.Hasher.html#method.fork
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, Murmur3Hasher};
+///
+/// let mut prefix = Murmur3Hasher::default();
+/// prefix.write(b"sensor/");
+///
+/// let suffixes = ["temp", "humidity", "pressure"];
+/// for (hash, suffix) in hash32::hash_suffixes(&prefix, &suffixes).zip(suffixes.iter()) {
+///     assert_eq!(hash, hash32::murmur3(format!("sensor/{}", suffix).as_bytes(), 0));
+/// }
+/// ```
+pub fn hash_suffixes<'a, H, I>(prefix: &'a H, suffixes: I) -> impl Iterator<Item = u32> + 'a
+where
+    H: Clone + Hasher,
+    I: IntoIterator,
+    I::IntoIter: 'a,
+    I::Item: AsRef<[u8]>,
+{
+    suffixes.into_iter().map(move |suffix| {
+        let mut hasher = prefix.fork();
+        hasher.write(suffix.as_ref());
+        hasher.finish()
+    })
+}
+
+/// Returns a `BuildHasher` of [`FnvHasher`](type.FnvHasher.html)s
+///
+/// Shorthand for `BuildHasherDefault::<FnvHasher>::default()`.
+///
+/// ```
+/// use hash32::BuildHasher;
+///
+/// let builder = hash32::fnv_build_hasher();
+///
+/// assert_eq!(builder.hash_one("foo"), builder.hash_one("foo"));
+/// ```
+pub const fn fnv_build_hasher() -> BuildHasherDefault<FnvHasher> {
+    BuildHasherDefault::new()
+}
+
+/// Returns a `BuildHasher` of [`Murmur3Hasher`](struct.Murmur3Hasher.html)s
+///
+/// Shorthand for `BuildHasherDefault::<Murmur3Hasher>::default()`.
+///
+/// ```
+/// use hash32::BuildHasher;
+///
+/// let builder = hash32::murmur3_build_hasher();
+///
+/// assert_eq!(builder.hash_one("foo"), builder.hash_one("foo"));
+/// ```
+pub const fn murmur3_build_hasher() -> BuildHasherDefault<Murmur3Hasher> {
+    BuildHasherDefault::new()
+}
+
+macro_rules! int {
+    ($ty:ident, $write:ident) => {
+        impl Hash for $ty {
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                state.$write(*self)
+            }
+
+            fn hash_slice<H>(data: &[Self], state: &mut H)
+            where
+                H: Hasher,
+            {
+                if state.is_split_insensitive() {
+                    // encode the elements into a buffer and feed it 64 bytes per `write`
+                    let mut buf = [0; 64];
+                    for chunk in data.chunks(buf.len() / mem::size_of::<$ty>()) {
+                        let mut len = 0;
+                        for piece in chunk {
+                            let bytes = piece.to_le_bytes();
+                            buf[len..len + bytes.len()].copy_from_slice(&bytes);
+                            len += bytes.len();
+                        }
+                        state.write(&buf[..len]);
+                    }
+                } else {
+                    for piece in data {
+                        state.$write(*piece)
+                    }
+                }
+            }
+        }
+    };
+}
+
+int!(i128, write_i128);
+int!(i16, write_i16);
+int!(i32, write_i32);
+int!(i64, write_i64);
+int!(i8, write_i8);
+int!(isize, write_isize);
+int!(u128, write_u128);
+int!(u16, write_u16);
+int!(u32, write_u32);
+int!(u64, write_u64);
+int!(usize, write_usize);
+
+impl Hash for u8 {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_u8(*self)
+    }
+
+    // bytes have no endianness so the whole slice can be fed at once
+    fn hash_slice<H>(data: &[Self], state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write(data)
+    }
+}
+
+macro_rules! nonzero {
+    ($ty:ident) => {
+        impl Hash for $ty {
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                self.get().hash(state)
+            }
+        }
+    };
+}
+
+nonzero!(NonZeroI128);
+nonzero!(NonZeroI16);
+nonzero!(NonZeroI32);
+nonzero!(NonZeroI64);
+nonzero!(NonZeroI8);
+nonzero!(NonZeroIsize);
+nonzero!(NonZeroU128);
+nonzero!(NonZeroU16);
+nonzero!(NonZeroU32);
+nonzero!(NonZeroU64);
+nonzero!(NonZeroU8);
+nonzero!(NonZeroUsize);
+
+/// Slices of `bool` are packed, 8 booleans per byte with the first one in the least significant
+/// bit, so they hash differently than feeding each `bool` on its own
+///
+/// The slice length is hashed before the packed bits, so masks that only differ in their trailing
+/// `false`s don't collide.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut mask = [false; 20];
+/// mask[0] = true;
+/// mask[9] = true;
+///
+/// let mut a = FnvHasher::default();
+/// mask.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// b.write_usize(20);
+/// b.write(&[0b0000_0001, 0b0000_0010, 0b0000_0000]);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for bool {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (*self as u8).hash(state)
+    }
+
+    fn hash_slice<H>(data: &[Self], state: &mut H)
+    where
+        H: Hasher,
+    {
+        // 256 booleans per `write`
+        let mut buf = [0; 32];
+        for chunk in data.chunks(8 * buf.len()) {
+            let len = chunk.len().div_ceil(8);
+            for (byte, bits) in buf.iter_mut().zip(chunk.chunks(8)) {
+                *byte = bits
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, bit)| byte | (*bit as u8) << i);
+            }
+            state.write(&buf[..len]);
+        }
+    }
+}
+
+/// Hashed as its Unicode scalar value, encoded like a `u32`
+///
+/// A `[char]` is hashed as its length followed by the 4-byte value of every `char`. This is not
+/// how the equivalent `str` is hashed, as UTF-8 bytes, so a `[char]` and a `str` with the same
+/// text should not be assumed to hash equal.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// ['a', 'b'][..].hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (2usize, 0x61u32, 0x62u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for char {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_u32(*self as u32)
+    }
+
+    fn hash_slice<H>(data: &[Self], state: &mut H)
+    where
+        H: Hasher,
+    {
+        for c in data {
+            state.write_u32(*c as u32)
+        }
+    }
+}
+
+/// A float is hashed as its bit pattern, encoded like a `u32`
+///
+/// `-0.0` is hashed as `+0.0` and every NaN as the canonical quiet NaN (`0x7fc0_0000`) so that
+/// values that compare equal, and all NaNs, hash the same. Only integer operations are used.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// (-0.0f32).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 0.0f32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// The normalization carries over to derived implementations, so a key that is also ordered by a
+/// float, e.g. in a `BTreeMap`, can be hashed consistently with how it compares.
+///
+/// ```
+/// #[macro_use]
+/// extern crate hash32_derive;
+/// extern crate hash32;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// #[derive(Hash32)]
+/// struct Score {
+///     value: f32,
+///     id: u16,
+/// }
+///
+/// # fn main() {
+/// let mut a = FnvHasher::default();
+/// Score { value: 0.0, id: 1 }.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// Score { value: -0.0, id: 1 }.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// # }
+/// ```
+impl Hash for f32 {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let bits = self.to_bits();
+        let bits = if bits & 0x7fff_ffff == 0 {
+            0
+        } else if bits & 0x7fff_ffff > 0x7f80_0000 {
+            0x7fc0_0000
+        } else {
+            bits
+        };
+        state.write_u32(bits)
+    }
+}
+
+/// A float is hashed as its bit pattern, encoded like a `u64`
+///
+/// `-0.0` is hashed as `+0.0` and every NaN as the canonical quiet NaN
+/// (`0x7ff8_0000_0000_0000`) so that values that compare equal, and all NaNs, hash the same. The
+/// little endian bytes of the bit pattern are split into two 32-bit halves, so the normalization
+/// uses only 32-bit integer operations.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// f64::NAN.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (-f64::NAN).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for f64 {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let bytes = self.to_bits().to_le_bytes();
+        let (low, high) = (LE::read_u32(&bytes[..4]), LE::read_u32(&bytes[4..]));
+        let magnitude = high & 0x7fff_ffff;
+        let (high, low) = if magnitude == 0 && low == 0 {
+            (0, 0)
+        } else if magnitude > 0x7ff0_0000 || (magnitude == 0x7ff0_0000 && low != 0) {
+            (0x7ff8_0000, 0)
+        } else {
+            (high, low)
+        };
+        // same bytes as `write_u64`
+        state.write_u32(low);
+        state.write_u32(high);
+    }
+}
+
+/// Hashed as its UTF-8 bytes followed by a `0xff` terminator
+///
+/// `0xff` never appears in UTF-8 so the terminator keeps adjacent strings apart. See
+/// [`RawStr`](struct.RawStr.html) to hash only the bytes of a string.
+impl Hash for str {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write(self.as_bytes());
+        state.write(&[0xff]);
+    }
+}
+
+/// Hashed as its bytes followed by the NUL terminator
+///
+/// A `str` is terminated with `0xff` instead, so a `CStr` and the `str` with the same contents hash
+/// differently.
+/// See [`CBuf`](struct.CBuf.html) to hash a NUL-padded `c_char` buffer the same way.
+///
+/// ```
+/// use std::ffi::CStr;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut hasher = FnvHasher::default();
+/// CStr::from_bytes_with_nul(b"foo\0").unwrap().hash(&mut hasher);
+///
+/// assert_eq!(hasher.finish(), hash32::fnv1a(b"foo\0"));
+/// ```
+impl Hash for CStr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write(self.to_bytes_with_nul());
+    }
+}
+
+/// The unit type doesn't feed anything to the hasher
+impl Hash for () {
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+/// `PhantomData` doesn't feed anything to the hasher
+///
+/// ```
+/// #[macro_use]
+/// extern crate hash32_derive;
+/// extern crate hash32;
+///
+/// use std::marker::PhantomData;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// #[derive(Hash32)]
+/// struct Tagged {
+///     value: u32,
+///     _tag: PhantomData<u8>,
+/// }
+///
+/// # fn main() {
+/// let mut a = FnvHasher::default();
+/// Tagged { value: 42, _tag: PhantomData }.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 42u32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// # }
+/// ```
+impl<T> Hash for PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+/// `None` is hashed as the byte `0`; `Some(x)` as the byte `1` followed by `x`
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Some(0u32).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (1u8, 0u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+///
+/// An `Option` of a reference hashes like an `Option` of the referenced value.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Some(&5u32).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// Some(5u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for Option<T>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            None => state.write_u8(0),
+            Some(ref x) => {
+                state.write_u8(1);
+                x.hash(state);
+            }
+        }
+    }
+}
+
+/// `Ok(x)` is hashed as the byte `0` followed by `x`; `Err(e)` as the byte `1` followed by `e`
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Err::<u16, u16>(7).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (1u8, 7u16).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T, E> Hash for Result<T, E>
+where
+    T: Hash,
+    E: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            Ok(ref x) => {
+                state.write_u8(0);
+                x.hash(state);
+            }
+            Err(ref e) => {
+                state.write_u8(1);
+                e.hash(state);
+            }
+        }
+    }
+}
+
+/// Hashed as its length, encoded like a `usize`, followed by its elements
+///
+/// The length prefix means that nested slices and arrays can't be confused with each other when
+/// their elements are split at different boundaries, e.g. `[[1], [2, 3]]` and `[[1, 2], [3]]`
+/// feed different bytes to the hasher even though their flattened contents are equal. Each
+/// level of nesting gets its own prefix. See [`Unprefixed`](struct.Unprefixed.html) to hash a
+/// slice without it.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let nested: &[&[u8]] = &[&[1], &[2, 3]];
+///
+/// let mut a = FnvHasher::default();
+/// nested.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// b.write_usize(2);
+/// for inner in nested {
+///     b.write_usize(inner.len());
+///     b.write(inner);
+/// }
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for [T]
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().hash(state);
+        T::hash_slice(self, state);
+    }
+}
+
+/// Hashed like the slice `&self[..]`, i.e. its length followed by its elements
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// [7u32; 40].hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// [7u32; 40][..].hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T, const N: usize> Hash for [T; N]
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        Hash::hash(&self[..], state)
+    }
+}
+
+macro_rules! last_type {
+    ($a:ident,) => { $a };
+    ($a:ident, $($rest:ident,)+) => { last_type!($($rest,)+) };
+}
+
+// like in `core`, the last element of a tuple may be unsized
+macro_rules! tuple {
+    ($($name:ident)+) => {
+        impl<$($name: Hash),+> Hash for ($($name,)+)
+        where
+            last_type!($($name,)+): ?Sized,
+        {
+            #[allow(non_snake_case)]
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                let ($(ref $name,)+) = *self;
+                $($name.hash(state);)+
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G I);
+tuple!(A B C D E F G I J);
+tuple!(A B C D E F G I J K);
+tuple!(A B C D E F G I J K L);
+tuple!(A B C D E F G I J K L M);
+
+impl<T: ?Sized + Hash> Hash for &T {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for &mut T {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+/// Hashed as its address, encoded like a `usize`
+///
+/// Only the address is hashed: the metadata of a wide pointer, i.e. the length of a slice or the
+/// vtable of a trait object, is not part of the hash. The pointee is never read.
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let x = 0u32;
+/// let ptr: *const u32 = &x;
+///
+/// let mut a = FnvHasher::default();
+/// ptr.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (ptr as usize).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T: ?Sized> Hash for *const T {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.cast::<()>() as usize);
+    }
+}
+
+/// Hashed as its address, like a `*const T`
+impl<T: ?Sized> Hash for *mut T {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self as *const T).hash(state);
+    }
+}
+
+/// Hashed as its address, like a `*const T`
+///
+/// ```
+/// use std::ptr::NonNull;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut x = 0u32;
+/// let ptr = NonNull::from(&mut x);
+///
+/// let mut a = FnvHasher::default();
+/// ptr.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (ptr.as_ptr() as *const u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T: ?Sized> Hash for NonNull<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state);
+    }
+}
+
+/// Hashed as its 4 octets, like `[u8; 4]`
+///
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Ipv4Addr::new(127, 0, 0, 1).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// [127u8, 0, 0, 1].hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for Ipv4Addr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.octets().hash(state)
+    }
+}
+
+/// Hashed as its 16 octets, like `[u8; 16]`
+///
+/// ```
+/// use std::net::Ipv6Addr;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Ipv6Addr::LOCALHOST.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// Ipv6Addr::LOCALHOST.octets().hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for Ipv6Addr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.octets().hash(state)
+    }
+}
+
+/// `V4(addr)` is hashed as the byte `0` followed by `addr`; `V6(addr)` as the byte `1` followed by
+/// `addr`
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let ip = Ipv4Addr::new(10, 0, 0, 1);
+///
+/// let mut a = FnvHasher::default();
+/// IpAddr::V4(ip).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (0u8, ip).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for IpAddr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            IpAddr::V4(ref addr) => {
+                state.write_u8(0);
+                addr.hash(state);
+            }
+            IpAddr::V6(ref addr) => {
+                state.write_u8(1);
+                addr.hash(state);
+            }
+        }
+    }
+}
+
+/// Hashed as its IP address followed by its port
+///
+/// ```
+/// use std::net::{Ipv4Addr, SocketAddrV4};
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let ip = Ipv4Addr::new(192, 168, 0, 1);
+///
+/// let mut a = FnvHasher::default();
+/// SocketAddrV4::new(ip, 80).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (ip, 80u16).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for SocketAddrV4 {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.ip().hash(state);
+        self.port().hash(state);
+    }
+}
+
+/// Hashed as its IP address followed by its port, flow info and scope ID
+///
+/// ```
+/// use std::net::{Ipv6Addr, SocketAddrV6};
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let ip = Ipv6Addr::LOCALHOST;
+///
+/// let mut a = FnvHasher::default();
+/// SocketAddrV6::new(ip, 80, 1, 2).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (ip, 80u16, 1u32, 2u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for SocketAddrV6 {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.ip().hash(state);
+        self.port().hash(state);
+        self.flowinfo().hash(state);
+        self.scope_id().hash(state);
+    }
+}
+
+/// `V4(addr)` is hashed as the byte `0` followed by `addr`; `V6(addr)` as the byte `1` followed by
+/// `addr`
+///
+/// ```
+/// use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let ip = Ipv4Addr::new(10, 0, 0, 1);
+///
+/// let mut a = FnvHasher::default();
+/// SocketAddr::V4(SocketAddrV4::new(ip, 80)).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (0u8, ip, 80u16).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for SocketAddr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            SocketAddr::V4(ref addr) => {
+                state.write_u8(0);
+                addr.hash(state);
+            }
+            SocketAddr::V6(ref addr) => {
+                state.write_u8(1);
+                addr.hash(state);
+            }
+        }
+    }
+}
+
+/// Hashed as the whole seconds, a `u64`, followed by the subsecond nanoseconds, a `u32`
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Duration::new(5, 100).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (5u64, 100u32).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for Duration {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_secs().hash(state);
+        self.subsec_nanos().hash(state);
+    }
+}
+
+/// Hashed as `start` followed by `end`
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// (0u16..5).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (0u16, 5u16).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<Idx> Hash for Range<Idx>
+where
+    Idx: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+/// Hashed as `start()` followed by `end()` and the byte `1`, which keeps it apart from the
+/// `Range` with the same bounds
+///
+/// Whether the range has been exhausted by iteration is not observable, so it isn't hashed.
+impl<Idx> Hash for RangeInclusive<Idx>
+where
+    Idx: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.start().hash(state);
+        self.end().hash(state);
+        state.write_u8(1);
+    }
+}
+
+/// Hashed as `start`
+impl<Idx> Hash for RangeFrom<Idx>
+where
+    Idx: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.start.hash(state);
+    }
+}
+
+/// Hashed as `end`
+impl<Idx> Hash for RangeTo<Idx>
+where
+    Idx: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.end.hash(state);
+    }
+}
+
+/// Hashed as `end` followed by the byte `1`, which keeps it apart from the `RangeTo` with the
+/// same bound
+impl<Idx> Hash for RangeToInclusive<Idx>
+where
+    Idx: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.end.hash(state);
+        state.write_u8(1);
+    }
+}
+
+/// `..` doesn't feed anything to the hasher
+impl Hash for RangeFull {
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+/// `Included(x)` is hashed as the byte `0` followed by `x`, `Excluded(x)` as the byte `1` followed
+/// by `x` and `Unbounded` as the byte `2`
+///
+/// ```
+/// use std::ops::Bound;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Bound::Excluded(5u16).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (1u8, 5u16).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for Bound<T>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            Bound::Included(ref x) => {
+                state.write_u8(0);
+                x.hash(state);
+            }
+            Bound::Excluded(ref x) => {
+                state.write_u8(1);
+                x.hash(state);
+            }
+            Bound::Unbounded => state.write_u8(2),
         }
-    };
+    }
 }
 
-int!(i16);
-int!(i32);
-int!(i64);
-int!(i8);
-int!(isize);
-int!(u16);
-int!(u32);
-int!(u64);
-int!(u8);
-int!(usize);
+/// `Continue(c)` is hashed as the byte `0` followed by `c`; `Break(b)` as the byte `1` followed by
+/// `b`
+///
+/// ```
+/// use std::ops::ControlFlow;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// ControlFlow::<u8, u8>::Break(3).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (1u8, 3u8).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<B, C> Hash for ControlFlow<B, C>
+where
+    B: Hash,
+    C: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            ControlFlow::Continue(ref c) => {
+                state.write_u8(0);
+                c.hash(state);
+            }
+            ControlFlow::Break(ref b) => {
+                state.write_u8(1);
+                b.hash(state);
+            }
+        }
+    }
+}
 
-impl Hash for bool {
+/// Hashed as its discriminant, `-1`, `0` or `1`, encoded like an `i8`
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Ordering::Less.hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// (-1i8).hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl Hash for Ordering {
     fn hash<H>(&self, state: &mut H)
     where
         H: Hasher,
     {
-        (*self as u8).hash(state)
+        (*self as i8).hash(state)
     }
 }
 
-impl Hash for char {
+/// Hashed like the value it wraps
+impl<T> Hash for Reverse<T>
+where
+    T: Hash,
+{
     fn hash<H>(&self, state: &mut H)
     where
         H: Hasher,
     {
-        (*self as u32).hash(state)
+        self.0.hash(state)
     }
 }
 
-impl Hash for str {
+/// Hashed like the value it wraps
+///
+/// ```
+/// use std::num::Wrapping;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Wrapping(7u8).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 7u8.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for Wrapping<T>
+where
+    T: Hash,
+{
     fn hash<H>(&self, state: &mut H)
     where
         H: Hasher,
     {
-        state.write(self.as_bytes());
-        state.write(&[0xff]);
+        self.0.hash(state)
     }
 }
 
-impl<T> Hash for [T]
+/// Hashed like the value it wraps
+///
+/// ```
+/// use std::num::Saturating;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Saturating(7u8).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 7u8.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for Saturating<T>
 where
     T: Hash,
 {
@@ -242,42 +2602,612 @@ where
     where
         H: Hasher,
     {
-        self.len().hash(state);
-        T::hash_slice(self, state);
+        self.0.hash(state)
+    }
+}
+
+/// Hashed like the value it points to
+#[cfg(feature = "alloc")]
+impl<T> Hash for Box<T>
+where
+    T: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state)
+    }
+}
+
+/// Hashed like the equivalent `str`
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// String::from("foo").hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// "foo".hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[cfg(feature = "alloc")]
+impl Hash for String {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_str().hash(state)
+    }
+}
+
+/// Hashed like the equivalent slice
+#[cfg(feature = "alloc")]
+impl<T> Hash for Vec<T>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_slice().hash(state)
+    }
+}
+
+/// Hashed like the value it points to, not like its address
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Rc::new(5u32).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 5u32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Hash for Rc<T>
+where
+    T: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state)
+    }
+}
+
+/// Hashed like the value it points to, not like its address
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Arc::new(5u32).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 5u32.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+// `Arc` is not available on targets without atomic pointers
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Hash for Arc<T>
+where
+    T: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state)
     }
 }
 
-macro_rules! array {
-    ($($n:expr),+) => {
-        $(
-            impl<T> Hash for [T; $n]
-                where
-                T: Hash,
+macro_rules! atomic {
+    ($ty:ident, $width:tt) => {
+        /// Hashed like the value returned by `load(Ordering::Relaxed)`
+        ///
+        /// The hash is a snapshot of the value at the time of the load; it doesn't synchronize
+        /// with other threads and it goes stale as soon as the value is modified.
+        #[cfg(target_has_atomic = $width)]
+        impl Hash for atomic::$ty {
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
             {
-                fn hash<H>(&self, state: &mut H)
-                    where
-                    H: Hasher,
-                {
-                    Hash::hash(&self[..], state)
-                }
+                self.load(atomic::Ordering::Relaxed).hash(state)
             }
-        )+
+        }
     };
 }
 
-array!(
-    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
-    26, 27, 28, 29, 30, 31, 32
-);
+atomic!(AtomicI16, "16");
+atomic!(AtomicI32, "32");
+atomic!(AtomicI64, "64");
+atomic!(AtomicI8, "8");
+atomic!(AtomicIsize, "ptr");
+atomic!(AtomicU16, "16");
+atomic!(AtomicU32, "32");
+atomic!(AtomicU64, "64");
+atomic!(AtomicU8, "8");
+atomic!(AtomicUsize, "ptr");
 
-impl<'a, T: ?Sized + Hash> Hash for &'a T {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        (**self).hash(state);
+/// Hashed like the value it wraps
+///
+/// ```
+/// use std::mem::ManuallyDrop;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// ManuallyDrop::new(7u16).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 7u16.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for ManuallyDrop<T>
+where
+    T: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state)
     }
 }
 
-impl<'a, T: ?Sized + Hash> Hash for &'a mut T {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        (**self).hash(state);
+/// Hashed like the value returned by `get`
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use hash32::{FnvHasher, Hash, Hasher};
+///
+/// let mut a = FnvHasher::default();
+/// Cell::new(7u16).hash(&mut a);
+///
+/// let mut b = FnvHasher::default();
+/// 7u16.hash(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+impl<T> Hash for Cell<T>
+where
+    T: Copy + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.get().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use core::ffi::{c_char, CStr};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+    use core::ops::{Bound, ControlFlow};
+    use core::ptr::NonNull;
+    use core::time::Duration;
+
+    #[cfg(feature = "test")]
+    use RecordingHasher;
+    use {
+        AddSumHasher, Adler32Hasher, CBuf, CityHash32Hasher, Crc32Hasher, Crc32cHasher,
+        Djb2AddHasher, Djb2Hasher, ElfHasher, Fletcher32Hasher, Fnv1Hasher, FnvHasher, Hash,
+        Hasher, JenkinsOaatHasher, KnuthHasher, Lookup3Hasher, Murmur2Hasher, Murmur2aHasher,
+        Murmur3Hasher, Pair, PearsonHasher, SaltedFnvHasher, SdbmHasher, SuperFastHasher,
+        UnorderedCombine, WangHasher, WyHash32Hasher, XorSumHasher, XxHash32,
+    };
+
+    fn hash<T: ?Sized + Hash>(x: &T) -> u32 {
+        let mut hasher = FnvHasher::default();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn tuple() {
+        assert_eq!(hash(&(&"key", 3u8)), hash(&("key", 3u8)));
+        assert_ne!(hash(&(&"key", 3u8)), hash(&(&"keyx", 3u8)));
+
+        let bytes: &[u8] = &[1, 2];
+        assert_eq!(hash(&(0u16, bytes)), hash(&(0u16, [1u8, 2])));
+
+        fn assert_hash<T: ?Sized + Hash>() {}
+        assert_hash::<(u16, [u8])>();
+        assert_hash::<(u8, str)>();
+    }
+
+    #[test]
+    fn bool_slice() {
+        let mut mask = [false; 20];
+        mask[0] = true;
+        mask[9] = true;
+
+        let mut packed = FnvHasher::default();
+        packed.write_usize(20);
+        packed.write(&[0b0000_0001, 0b0000_0010, 0b0000_0000]);
+        assert_eq!(hash(&mask), packed.finish());
+
+        let mut other = mask;
+        other[19] = true;
+        assert_ne!(hash(&mask), hash(&other));
+
+        assert_ne!(hash(&[true][..]), hash(&[true, false][..]));
+    }
+
+    #[test]
+    fn char() {
+        assert_eq!(hash(&'a'), hash(&0x61u32));
+
+        let ab = &['a', 'b'][..];
+        assert_ne!(hash(ab), hash(&['b', 'a'][..]));
+        assert_eq!(hash(ab), hash(&(2usize, 0x61u32, 0x62u32)));
+        assert_ne!(hash(ab), hash("ab"));
+    }
+
+    #[test]
+    fn f32() {
+        assert_eq!(hash(&0.0f32), hash(&-0.0f32));
+        assert_eq!(hash(&1.5f32), hash(&1.5f32.to_bits()));
+        assert_eq!(hash(&f32::NAN), hash(&-f32::NAN));
+        assert_eq!(hash(&f32::from_bits(0x7f80_0001)), hash(&0x7fc0_0000u32));
+        assert_ne!(hash(&f32::NAN), hash(&f32::INFINITY));
+    }
+
+    #[test]
+    fn f64() {
+        assert_eq!(hash(&0.0f64), hash(&-0.0f64));
+        assert_eq!(hash(&1.5f64), hash(&1.5f64.to_bits()));
+        assert_eq!(hash(&f64::NAN), hash(&-f64::NAN));
+        assert_eq!(
+            hash(&f64::from_bits(0x7ff0_0000_0000_0001)),
+            hash(&0x7ff8_0000_0000_0000u64)
+        );
+        assert_ne!(hash(&f64::NAN), hash(&f64::INFINITY));
+    }
+
+    #[test]
+    fn c_str() {
+        let foo = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+        let bar = CStr::from_bytes_with_nul(b"bar\0").unwrap();
+
+        assert_eq!(hash(foo), ::fnv1a(b"foo\0"));
+        assert_ne!(hash(foo), hash(bar));
+        assert_ne!(hash(foo), hash("foo"));
+    }
+
+    #[test]
+    fn c_buf() {
+        fn buf(bytes: &[u8; 8]) -> [c_char; 8] {
+            bytes.map(|b| b as c_char)
+        }
+
+        let zeroed = buf(b"eth0\0\0\0\0");
+        let garbage = buf(b"eth0\0\xaa\x55\xff");
+
+        assert_eq!(hash(&CBuf(&zeroed)), hash(&CBuf(&garbage)));
+        assert_eq!(
+            hash(&CBuf(&zeroed)),
+            hash(CStr::from_bytes_with_nul(b"eth0\0").unwrap())
+        );
+        assert_ne!(hash(&CBuf(&zeroed)), hash(&CBuf(&buf(b"eth1\0\0\0\0"))));
+
+        // without a NUL the whole buffer is hashed
+        assert_eq!(hash(&CBuf(&buf(b"abcdefgh"))), ::fnv1a(b"abcdefgh\0"));
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(hash(&None::<u32>), hash(&0u8));
+        assert_eq!(hash(&Some(0u32)), hash(&(1u8, 0u32)));
+        assert_ne!(hash(&None::<u32>), hash(&Some(0u32)));
+
+        assert_ne!(hash(&None::<Option<u8>>), hash(&Some(None::<u8>)));
+        assert_ne!(hash(&Some(None::<u8>)), hash(&Some(Some(0u8))));
+    }
+
+    #[test]
+    fn option_ref() {
+        assert_eq!(hash(&Some(&5u32)), hash(&Some(5u32)));
+        assert_eq!(hash(&None::<&u32>), hash(&None::<u32>));
+        assert_eq!(hash(&Some(&"k")), hash(&Some("k")));
+        assert_eq!(hash(&Some("k")), hash(&(1u8, "k")));
+        assert_ne!(hash(&Some("k")), hash(&None::<&str>));
+    }
+
+    #[test]
+    fn result() {
+        assert_eq!(hash(&Ok::<u16, u16>(7)), hash(&(0u8, 7u16)));
+        assert_eq!(hash(&Err::<u16, u16>(7)), hash(&(1u8, 7u16)));
+        assert_ne!(hash(&Ok::<u16, u16>(7)), hash(&Err::<u16, u16>(7)));
+
+        let ok: Result<Result<u8, u8>, u8> = Ok(Err(1));
+        let err: Result<Result<u8, u8>, u8> = Err(1);
+        assert_ne!(hash(&ok), hash(&err));
+    }
+
+    #[test]
+    fn slice() {
+        let a: &[&[u8]] = &[&[1], &[2, 3]];
+        let b: &[&[u8]] = &[&[1, 2], &[3]];
+        assert_ne!(hash(a), hash(b));
+        assert_eq!(hash(a), hash(&(2usize, 1usize, 1u8, 2usize, 2u8, 3u8)));
+
+        let nested = [[1u8, 2], [3, 4], [5, 6]];
+        let mut manual = FnvHasher::default();
+        manual.write_usize(3);
+        for inner in nested.iter() {
+            manual.write_usize(2);
+            manual.write(inner);
+        }
+        assert_eq!(hash(&nested), manual.finish());
+        assert_ne!(hash(&nested), hash(&[[1u8, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    fn array() {
+        assert_eq!(hash(&[0u8; 0]), hash(&0usize));
+        assert_eq!(hash(&[7u32; 40]), hash(&[7u32; 40][..]));
+
+        let mut hasher = FnvHasher::default();
+        hasher.write_usize(2);
+        hasher.write(&[1, 2]);
+        assert_eq!(hash(&[1u8, 2]), hasher.finish());
+    }
+
+    #[test]
+    fn pointer() {
+        let (a, b) = (0u32, 0u32);
+        let pa: *const u32 = &a;
+        let pb: *const u32 = &b;
+
+        assert_eq!(hash(&pa), hash(&(pa as usize)));
+        assert_ne!(hash(&pa), hash(&pb));
+
+        // the metadata of a wide pointer is not hashed
+        let bytes = [0u8; 4];
+        let slice: *const [u8] = &bytes[..];
+        assert_eq!(hash(&slice), hash(&bytes.as_ptr()));
+
+        let mut x = 0u32;
+        let ptr = NonNull::from(&mut x);
+        assert_eq!(hash(&ptr), hash(&(ptr.as_ptr() as *const u32)));
+    }
+
+    #[test]
+    fn ip_addr() {
+        assert_eq!(hash(&Ipv4Addr::new(127, 0, 0, 1)), hash(&[127u8, 0, 0, 1]));
+        assert_ne!(
+            hash(&Ipv4Addr::new(127, 0, 0, 1)),
+            hash(&Ipv4Addr::new(127, 0, 0, 2))
+        );
+
+        assert_eq!(
+            hash(&Ipv6Addr::LOCALHOST),
+            hash(&Ipv6Addr::LOCALHOST.octets())
+        );
+        assert_ne!(hash(&Ipv6Addr::LOCALHOST), hash(&Ipv6Addr::UNSPECIFIED));
+
+        let v4 = Ipv4Addr::new(0, 0, 0, 1);
+        assert_eq!(hash(&IpAddr::V4(v4)), hash(&(0u8, v4)));
+        assert_ne!(
+            hash(&IpAddr::V4(v4)),
+            hash(&IpAddr::V6(v4.to_ipv6_compatible()))
+        );
+        assert_ne!(
+            hash(&IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            hash(&IpAddr::V6(Ipv6Addr::UNSPECIFIED))
+        );
+    }
+
+    #[test]
+    fn socket_addr() {
+        let ip = Ipv4Addr::new(192, 168, 0, 1);
+        assert_eq!(hash(&SocketAddrV4::new(ip, 80)), hash(&(ip, 80u16)));
+        assert_ne!(
+            hash(&SocketAddrV4::new(ip, 80)),
+            hash(&SocketAddrV4::new(ip, 8080))
+        );
+
+        let ip6 = Ipv6Addr::LOCALHOST;
+        assert_eq!(
+            hash(&SocketAddrV6::new(ip6, 80, 1, 2)),
+            hash(&(ip6, 80u16, 1u32, 2u32))
+        );
+        assert_ne!(
+            hash(&SocketAddrV6::new(ip6, 80, 0, 0)),
+            hash(&SocketAddrV6::new(ip6, 8080, 0, 0))
+        );
+        assert_ne!(
+            hash(&SocketAddrV6::new(ip6, 80, 0, 0)),
+            hash(&SocketAddrV6::new(ip6, 80, 0, 1))
+        );
+
+        let v4 = SocketAddr::V4(SocketAddrV4::new(ip, 80));
+        let v6 = SocketAddr::V6(SocketAddrV6::new(ip.to_ipv6_mapped(), 80, 0, 0));
+        assert_eq!(hash(&v4), hash(&(0u8, ip, 80u16)));
+        assert_ne!(hash(&v4), hash(&v6));
+    }
+
+    #[test]
+    fn duration() {
+        let d = Duration::new(5, 100);
+        assert_eq!(hash(&d), hash(&(5u64, 100u32)));
+        assert_ne!(hash(&d), hash(&Duration::new(5, 101)));
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(hash(&(0u16..5)), hash(&(0u16, 5u16)));
+        assert_ne!(hash(&(0u16..5)), hash(&(0u16..=5)));
+        assert_eq!(hash(&(3u16..)), hash(&3u16));
+    }
+
+    #[test]
+    fn bound() {
+        assert_eq!(hash(&Bound::Included(5u16)), hash(&(0u8, 5u16)));
+        assert_eq!(hash(&Bound::Excluded(5u16)), hash(&(1u8, 5u16)));
+        assert_eq!(hash(&Bound::<u16>::Unbounded), hash(&2u8));
+        assert_ne!(hash(&Bound::Included(5u16)), hash(&Bound::Excluded(5u16)));
+    }
+
+    #[test]
+    fn control_flow() {
+        assert_eq!(hash(&ControlFlow::<u8, u8>::Continue(3)), hash(&(0u8, 3u8)));
+        assert_eq!(hash(&ControlFlow::<u8, u8>::Break(3)), hash(&(1u8, 3u8)));
+        assert_ne!(
+            hash(&ControlFlow::<u8, u8>::Continue(3)),
+            hash(&ControlFlow::<u8, u8>::Break(3))
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert_eq!(hash(&Ordering::Less), hash(&-1i8));
+        assert_ne!(hash(&Ordering::Less), hash(&Ordering::Equal));
+        assert_ne!(hash(&Ordering::Less), hash(&Ordering::Greater));
+        assert_ne!(hash(&Ordering::Equal), hash(&Ordering::Greater));
+    }
+
+    #[test]
+    fn unordered_combine() {
+        fn hash_set(elements: &[&str]) -> u32 {
+            let mut combine = UnorderedCombine::default();
+            for element in elements {
+                combine.insert(hash(element));
+            }
+            combine.finish()
+        }
+
+        assert_eq!(hash_set(&["a", "b", "c"]), hash_set(&["c", "a", "b"]));
+        assert_ne!(hash_set(&["a", "b", "c"]), hash_set(&["a", "b", "d"]));
+
+        // a repeated element cancels out in the XOR but not in the sum
+        assert_ne!(hash_set(&["a", "a", "b"]), hash_set(&["b"]));
+        // the number of elements counts too
+        assert_ne!(hash_set(&["a", "a"]), hash_set(&[]));
+    }
+
+    // The contract of `Hasher::is_split_insensitive`, for a hasher that returns `true`: the hash
+    // doesn't depend on how the bytes are split across `write` calls and every `write_*` method
+    // feeds the hasher the little endian bytes of the integer
+    fn check_split_insensitive<H: Default + Hasher>() {
+        assert!(H::default().is_split_insensitive());
+
+        let mut data = [0; 200];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(151).wrapping_add(29);
+        }
+
+        let mut whole = H::default();
+        whole.write(&data);
+        let whole = whole.finish();
+
+        for i in 0..=data.len() {
+            let mut hasher = H::default();
+            hasher.write(&data[..i]);
+            hasher.write(&data[i..]);
+            assert_eq!(hasher.finish(), whole, "split at {}", i);
+        }
+
+        for chunk in 1..20 {
+            let mut hasher = H::default();
+            for piece in data.chunks(chunk) {
+                hasher.write(piece);
+            }
+            assert_eq!(hasher.finish(), whole, "chunks of {}", chunk);
+        }
+
+        // after 0 to 4 bytes so the integer isn't always aligned with the blocks of the hasher
+        for prefix in 0..5 {
+            macro_rules! check_int {
+                ($($write:ident: $ty:ty),*) => {
+                    $(
+                        let x = 0x8967_4523_01ef_cdab_7856_3412_f0de_bc9au128 as $ty;
+
+                        let mut a = H::default();
+                        a.write(&data[..prefix]);
+                        a.$write(x);
+
+                        let mut b = H::default();
+                        b.write(&data[..prefix]);
+                        b.write(&x.to_le_bytes());
+
+                        assert_eq!(
+                            a.finish(),
+                            b.finish(),
+                            "{} after {} bytes",
+                            stringify!($write),
+                            prefix
+                        );
+                    )*
+                };
+            }
+
+            check_int!(
+                write_u8: u8,
+                write_u16: u16,
+                write_u32: u32,
+                write_u64: u64,
+                write_u128: u128,
+                write_usize: usize,
+                write_i8: i8,
+                write_i16: i16,
+                write_i32: i32,
+                write_i64: i64,
+                write_i128: i128,
+                write_isize: isize
+            );
+        }
+    }
+
+    #[test]
+    fn split_insensitive_hashers() {
+        check_split_insensitive::<Adler32Hasher>();
+        check_split_insensitive::<AddSumHasher>();
+        check_split_insensitive::<CityHash32Hasher>();
+        check_split_insensitive::<Crc32Hasher>();
+        check_split_insensitive::<Crc32cHasher>();
+        check_split_insensitive::<Djb2AddHasher>();
+        check_split_insensitive::<Djb2Hasher>();
+        check_split_insensitive::<ElfHasher>();
+        check_split_insensitive::<Fletcher32Hasher>();
+        check_split_insensitive::<Fnv1Hasher>();
+        check_split_insensitive::<FnvHasher>();
+        check_split_insensitive::<JenkinsOaatHasher>();
+        check_split_insensitive::<KnuthHasher>();
+        check_split_insensitive::<Lookup3Hasher>();
+        check_split_insensitive::<Murmur2Hasher>();
+        check_split_insensitive::<Murmur2aHasher>();
+        check_split_insensitive::<Murmur3Hasher>();
+        check_split_insensitive::<Pair<FnvHasher, Murmur3Hasher>>();
+        check_split_insensitive::<PearsonHasher>();
+        check_split_insensitive::<SaltedFnvHasher>();
+        check_split_insensitive::<SdbmHasher>();
+        check_split_insensitive::<SuperFastHasher>();
+        check_split_insensitive::<WangHasher>();
+        check_split_insensitive::<WyHash32Hasher>();
+        check_split_insensitive::<XorSumHasher>();
+        check_split_insensitive::<XxHash32>();
+        #[cfg(feature = "test")]
+        check_split_insensitive::<RecordingHasher>();
     }
 }
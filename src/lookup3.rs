@@ -0,0 +1,181 @@
+use byteorder::{ByteOrder, LE};
+
+/// Bob Jenkins' lookup3 (`hashlittle`) hasher
+///
+/// The reference `hashlittle(key, length, initval)` starts from `0xdeadbeef + length + initval`,
+/// so the total length of the input has to be known up front:
+/// `Lookup3Hasher::with_seed_and_len(initval, len)` reproduces the reference hash when exactly
+/// `len` bytes are then fed to it. With any other number of bytes the hash is still well defined,
+/// it just doesn't match the reference. `with_seed(initval)` and `Default` pass a `len` of `0`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, Lookup3Hasher};
+///
+/// // self-test values from lookup3.c
+/// assert_eq!(Lookup3Hasher::default().finish(), 0xdeadbeef);
+/// assert_eq!(Lookup3Hasher::with_seed(0xdeadbeef).finish(), 0xbd5b7dde);
+///
+/// let key = b"Four score and seven years ago";
+///
+/// let mut hasher = Lookup3Hasher::with_seed_and_len(0, key.len());
+/// hasher.write(key);
+/// assert_eq!(hasher.finish(), 0x17770551);
+///
+/// let mut hasher = Lookup3Hasher::with_seed_and_len(1, key.len());
+/// hasher.write(key);
+/// assert_eq!(hasher.finish(), 0xcd628161);
+/// ```
+pub struct Hasher {
+    buf: [u8; 12],
+    // number of bytes in `buf`; a full buffer is only mixed in once more input arrives because the
+    // last block goes through `final` instead of `mix`
+    index: usize,
+    state: State,
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+impl Hasher {
+    /// Creates a hasher that starts from the given `seed` and expects `len` bytes of input
+    pub fn with_seed_and_len(seed: u32, len: usize) -> Self {
+        let init = 0xdeadbeef_u32.wrapping_add(len as u32).wrapping_add(seed);
+        Hasher {
+            buf: [0; 12],
+            index: 0,
+            state: State {
+                a: init,
+                b: init,
+                c: init,
+            },
+        }
+    }
+
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed_and_len(seed, 0)
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        if self.index == 0 {
+            return self.state.c;
+        }
+
+        // zero padded tail
+        let mut block = [0; 12];
+        block[..self.index].copy_from_slice(&self.buf[..self.index]);
+
+        let mut state = self.state;
+        state.add(&block);
+        state.final_();
+        state.c
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            if self.index == 12 {
+                let buf = self.buf;
+                self.state.add(&buf);
+                self.state.mix();
+                self.index = 0;
+            }
+
+            let mid = (12 - self.index).min(bytes.len());
+            let (head, rest) = bytes.split_at(mid);
+            self.buf[self.index..self.index + mid].copy_from_slice(head);
+            self.index += mid;
+            bytes = rest;
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+impl State {
+    fn add(&mut self, block: &[u8; 12]) {
+        self.a = self.a.wrapping_add(LE::read_u32(&block[0..4]));
+        self.b = self.b.wrapping_add(LE::read_u32(&block[4..8]));
+        self.c = self.c.wrapping_add(LE::read_u32(&block[8..12]));
+    }
+
+    fn mix(&mut self) {
+        let State {
+            mut a,
+            mut b,
+            mut c,
+        } = *self;
+
+        a = a.wrapping_sub(c);
+        a ^= c.rotate_left(4);
+        c = c.wrapping_add(b);
+        b = b.wrapping_sub(a);
+        b ^= a.rotate_left(6);
+        a = a.wrapping_add(c);
+        c = c.wrapping_sub(b);
+        c ^= b.rotate_left(8);
+        b = b.wrapping_add(a);
+        a = a.wrapping_sub(c);
+        a ^= c.rotate_left(16);
+        c = c.wrapping_add(b);
+        b = b.wrapping_sub(a);
+        b ^= a.rotate_left(19);
+        a = a.wrapping_add(c);
+        c = c.wrapping_sub(b);
+        c ^= b.rotate_left(4);
+        b = b.wrapping_add(a);
+
+        *self = State { a, b, c };
+    }
+
+    // `final` in lookup3.c
+    fn final_(&mut self) {
+        let State {
+            mut a,
+            mut b,
+            mut c,
+        } = *self;
+
+        c ^= b;
+        c = c.wrapping_sub(b.rotate_left(14));
+        a ^= c;
+        a = a.wrapping_sub(c.rotate_left(11));
+        b ^= a;
+        b = b.wrapping_sub(a.rotate_left(25));
+        c ^= b;
+        c = c.wrapping_sub(b.rotate_left(16));
+        a ^= c;
+        a = a.wrapping_sub(c.rotate_left(4));
+        b ^= a;
+        b = b.wrapping_sub(a.rotate_left(14));
+        c ^= b;
+        c = c.wrapping_sub(b.rotate_left(24));
+
+        *self = State { a, b, c };
+    }
+}
@@ -0,0 +1,270 @@
+use byteorder::{ByteOrder, LE};
+
+const M: u32 = 0x5bd1e995;
+const R: u32 = 24;
+
+/// 32-bit MurmurHash2 hasher
+///
+/// The reference `MurmurHash2(key, len, seed)` starts from `seed ^ len`, so the total length of
+/// the input has to be known up front: `Murmur2Hasher::with_seed_and_len(seed, len)` reproduces
+/// the reference hash when exactly `len` bytes are then fed to it. With any other number of bytes
+/// the hash is still well defined, it just doesn't match the reference. `with_seed(seed)` and
+/// `Default` pass a `len` of `0`.
+///
+/// See [`Murmur2aHasher`] for the variant of MurmurHash2 that doesn't need the length up front.
+///
+/// [`Murmur2aHasher`]: struct.Murmur2aHasher.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, Murmur2Hasher};
+///
+/// let mut hasher = Murmur2Hasher::with_seed_and_len(0, 5);
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0xe56129cb);
+///
+/// let data = b"The quick brown fox jumps over the lazy dog";
+/// let mut hasher = Murmur2Hasher::with_seed_and_len(0x9747b28c, data.len());
+/// hasher.write(data);
+/// assert_eq!(hasher.finish(), 0x1d84d036);
+///
+/// assert_eq!(Murmur2Hasher::default().finish(), 0);
+///
+/// // computed with the reference C implementation
+/// let vectors: &[(&[u8], u32, u32)] = &[
+///     (b"", 0x9747b28c, 0x106e08d9),
+///     (b"a", 0, 0x92685f5e),
+///     (b"ab", 0x9747b28c, 0x12d8262a),
+///     (b"abc", 0, 0x13577c9b),
+///     (b"\xff\xfe\x80", 0x9747b28c, 0xf66b8283),
+/// ];
+/// for &(data, seed, hash) in vectors {
+///     let mut hasher = Murmur2Hasher::with_seed_and_len(seed, data.len());
+///     hasher.write(data);
+///     assert_eq!(hasher.finish(), hash);
+/// }
+/// ```
+pub struct Hasher {
+    blocks: Blocks,
+}
+
+impl Hasher {
+    /// Creates a hasher that starts from the given `seed` and expects `len` bytes of input
+    pub fn with_seed_and_len(seed: u32, len: usize) -> Self {
+        Hasher {
+            blocks: Blocks::new(seed ^ len as u32),
+        }
+    }
+
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed_and_len(seed, 0)
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.blocks.processed as usize
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        let mut state = self.blocks.state;
+        if self.blocks.count != 0 {
+            state ^= self.blocks.tail;
+            state = state.wrapping_mul(M);
+        }
+
+        fmix(state)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.blocks.write(bytes)
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+/// 32-bit MurmurHash2A hasher
+///
+/// MurmurHash2A is the variant of MurmurHash2, by the same author, that mixes the length of the
+/// input in at the end instead of into the initial state, so it can be computed incrementally. It
+/// uses the same `m` and `r` constants and per-block mixing but its output differs from
+/// [`Murmur2Hasher`].
+///
+/// [`Murmur2Hasher`]: struct.Murmur2Hasher.html
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, Murmur2aHasher};
+///
+/// let mut hasher = Murmur2aHasher::default();
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0x0f7e3bda);
+///
+/// let mut hasher = Murmur2aHasher::with_seed(0x9747b28c);
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0xe5809c92);
+///
+/// // computed with the reference C implementation
+/// let vectors: &[(&[u8], u32, u32)] = &[
+///     (b"", 0x9747b28c, 0xe37c4f59),
+///     (b"a", 0, 0x0803888b),
+///     (b"abc", 0x9747b28c, 0x4e0e2aa7),
+///     (b"\xff\xfe\x80", 0, 0x3ab0cc7b),
+/// ];
+/// for &(data, seed, hash) in vectors {
+///     let mut hasher = Murmur2aHasher::with_seed(seed);
+///     hasher.write(data);
+///     assert_eq!(hasher.finish(), hash);
+/// }
+/// ```
+pub struct Hasher2a {
+    blocks: Blocks,
+}
+
+impl Hasher2a {
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher2a {
+            blocks: Blocks::new(seed),
+        }
+    }
+}
+
+impl Default for Hasher2a {
+    fn default() -> Self {
+        Hasher2a::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher2a {
+    fn with_seed(seed: u32) -> Self {
+        Hasher2a::with_seed(seed)
+    }
+}
+
+impl ::CountingHasher for Hasher2a {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.blocks.processed as usize
+    }
+}
+
+impl ::generic::HasherN for Hasher2a {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        let mut state = mix(self.blocks.state, self.blocks.tail);
+        state = mix(state, self.blocks.processed);
+
+        fmix(state)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.blocks.write(bytes)
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+// The 4-byte block loop shared by MurmurHash2 and MurmurHash2A
+struct Blocks {
+    // incomplete block; its bytes are stored in little endian order
+    tail: u32,
+    // number of bytes in `tail`
+    count: u32,
+    processed: u32,
+    state: u32,
+}
+
+impl Blocks {
+    fn new(state: u32) -> Self {
+        Blocks {
+            tail: 0,
+            count: 0,
+            processed: 0,
+            state,
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.tail |= u32::from(byte) << (self.count * 8);
+        self.count += 1;
+
+        if self.count == 4 {
+            self.state = mix(self.state, self.tail);
+            self.tail = 0;
+            self.count = 0;
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.processed = self.processed.wrapping_add(bytes.len() as u32);
+
+        let mut bytes = bytes;
+
+        // complete the pending block first
+        while self.count != 0 {
+            match bytes.split_first() {
+                Some((byte, rest)) => {
+                    self.push(*byte);
+                    bytes = rest;
+                }
+                None => return,
+            }
+        }
+
+        for block in bytes.chunks(4) {
+            if block.len() == 4 {
+                self.state = mix(self.state, LE::read_u32(block));
+            } else {
+                for byte in block {
+                    self.push(*byte);
+                }
+            }
+        }
+    }
+}
+
+fn mix(state: u32, mut block: u32) -> u32 {
+    block = block.wrapping_mul(M);
+    block ^= block >> R;
+    block = block.wrapping_mul(M);
+    state.wrapping_mul(M) ^ block
+}
+
+// finalization mix
+fn fmix(mut state: u32) -> u32 {
+    state ^= state >> 13;
+    state = state.wrapping_mul(M);
+    state ^= state >> 15;
+    state
+}
@@ -1,8 +1,57 @@
-use core::{mem, slice};
+use core::slice;
 
 use byteorder::{ByteOrder, LE};
 
 /// 32-bit MurmurHash3 hasher
+///
+/// # Examples
+///
+/// The state can be copied to hash several inputs that share a prefix.
+///
+/// ```
+/// use hash32::{Hasher, Murmur3Hasher};
+///
+/// let mut prefix = Murmur3Hasher::default();
+/// prefix.write(b"foo");
+///
+/// let mut fork = prefix;
+/// fork.write(b"bar");
+/// prefix.write(b"bar");
+/// assert_eq!(fork, prefix);
+///
+/// let mut oneshot = Murmur3Hasher::default();
+/// oneshot.write(b"foobar");
+/// assert_eq!(fork.finish(), oneshot.finish());
+/// ```
+///
+/// Bytes that don't fill a 4-byte block are buffered until the next `write` so splitting the
+/// input across several `write` calls doesn't change the hash.
+///
+/// ```
+/// use hash32::{Hasher, Murmur3Hasher};
+///
+/// let bytes = b"The quick brown fox jumps over the lazy dog";
+///
+/// let mut oneshot = Murmur3Hasher::default();
+/// oneshot.write(bytes);
+///
+/// for i in 0..=bytes.len() {
+///     for j in i..=bytes.len() {
+///         let mut hasher = Murmur3Hasher::default();
+///         hasher.write(&bytes[..i]);
+///         hasher.write(&bytes[i..j]);
+///         hasher.write(&bytes[j..]);
+///         assert_eq!(hasher.finish(), oneshot.finish());
+///     }
+/// }
+///
+/// let mut bytewise = Murmur3Hasher::default();
+/// for byte in bytes.iter() {
+///     bytewise.write(&[*byte]);
+/// }
+/// assert_eq!(bytewise.finish(), oneshot.finish());
+/// ```
+#[derive(Clone, Copy, Debug)]
 pub struct Hasher {
     buf: Buffer,
     index: Index,
@@ -10,15 +59,16 @@ pub struct Hasher {
     state: State,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct State(u32);
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 #[repr(align(4))]
 struct Buffer {
     bytes: [u8; 4],
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Index {
     _0,
     _1,
@@ -50,35 +100,68 @@ impl From<usize> for Index {
 }
 
 impl Hasher {
-    fn push(&mut self, buf: &[u8]) {
-        let start = self.index.usize();
-        let len = buf.len();
-        // NOTE(unsafe) avoid calling `memcpy` on a 0-3 byte copy
-        // self.buf.bytes[start..start+len].copy_from(buf);
-        for i in 0..len {
-            unsafe {
-                *self.buf.bytes.get_unchecked_mut(start + i) = *buf.get_unchecked(i);
-            }
-        }
-        self.index = Index::from(start + len);
-    }
-}
-
-impl Default for Hasher {
-    fn default() -> Self {
+    /// Creates a hasher that starts from the given `seed`
+    ///
+    /// `with_seed(0)` is equivalent to `Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{Hasher, Murmur3Hasher};
+    ///
+    /// assert_eq!(Murmur3Hasher::with_seed(1).finish(), 0x514e28b7);
+    /// assert_eq!(Murmur3Hasher::with_seed(0xffff_ffff).finish(), 0x81f16f39);
+    ///
+    /// let mut hasher = Murmur3Hasher::with_seed(0x9747b28c);
+    /// hasher.write(b"Hello, world!");
+    /// assert_eq!(hasher.finish(), 0x24884cba);
+    ///
+    /// let mut hasher = Murmur3Hasher::with_seed(0x9747b28c);
+    /// hasher.write(b"The quick brown fox jumps over the lazy dog");
+    /// assert_eq!(hasher.finish(), 0x2fa826cd);
+    /// ```
+    pub fn with_seed(seed: u32) -> Self {
         Hasher {
-            buf: unsafe { mem::uninitialized() },
+            buf: Buffer { bytes: [0; 4] },
             index: Index::_0,
             processed: 0,
-            state: State(0),
+            state: State(seed),
         }
     }
-}
 
-impl ::Hasher for Hasher {
-    fn finish(&self) -> u32 {
+    /// Returns the hash without the final avalanche step
+    ///
+    /// This is the running state after mixing in the incomplete trailing block and the input
+    /// length, i.e. right before the `fmix32` finalization of the reference implementation.
+    /// Some file formats store this value instead of the finalized hash, which is what
+    /// [`finish`](trait.Hasher.html#tymethod.finish) returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash32::{Hasher, Murmur3Hasher};
+    ///
+    /// fn fmix32(mut h: u32) -> u32 {
+    ///     h ^= h >> 16;
+    ///     h = h.wrapping_mul(0x85ebca6b);
+    ///     h ^= h >> 13;
+    ///     h = h.wrapping_mul(0xc2b2ae35);
+    ///     h ^ (h >> 16)
+    /// }
+    ///
+    /// let mut hasher = Murmur3Hasher::with_seed(0x9747b28c);
+    /// hasher.write(b"Hello, world!");
+    ///
+    /// assert_eq!(hasher.finish(), 0x24884cba);
+    /// assert_ne!(hasher.finish_unmixed(), hasher.finish());
+    /// assert_eq!(fmix32(hasher.finish_unmixed()), hasher.finish());
+    ///
+    /// // with no input, the unmixed hash is the seed
+    /// assert_eq!(Murmur3Hasher::with_seed(42).finish_unmixed(), 42);
+    /// ```
+    pub fn finish_unmixed(&self) -> u32 {
         // tail
-        let mut state = match self.index {
+        let state = match self.index {
             Index::_3 => {
                 let mut block = 0;
                 block ^= u32::from(self.buf.bytes[2]) << 16;
@@ -100,21 +183,67 @@ impl ::Hasher for Hasher {
             Index::_0 => self.state.0,
         };
 
-        // finalization mix
-        state ^= self.processed;
-        state ^= state >> 16;
-        state = state.wrapping_mul(0x85ebca6b);
-        state ^= state >> 13;
-        state = state.wrapping_mul(0xc2b2ae35);
-        state ^= state >> 16;
+        state ^ self.processed
+    }
+
+    fn push(&mut self, buf: &[u8]) {
+        let start = self.index.usize();
+        let len = buf.len();
+        // NOTE(unsafe) avoid calling `memcpy` on a 0-3 byte copy
+        // self.buf.bytes[start..start+len].copy_from(buf);
+        for i in 0..len {
+            unsafe {
+                *self.buf.bytes.get_unchecked_mut(start + i) = *buf.get_unchecked(i);
+            }
+        }
+        self.index = Index::from(start + len);
+    }
+}
+
+// only the buffered bytes are compared; the rest of the buffer holds stale data
+impl PartialEq for Hasher {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.index.usize();
+        self.index == other.index
+            && self.processed == other.processed
+            && self.state == other.state
+            && self.buf.bytes[..len] == other.buf.bytes[..len]
+    }
+}
+
+impl Eq for Hasher {}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.processed as usize
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
 
-        state
+    fn finish(&self) -> u32 {
+        fmix(self.finish_unmixed())
     }
 
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
         let len = bytes.len();
-        self.processed += len as u32;
+        // like the reference implementation, only the length modulo 2^32 affects the hash
+        self.processed = self.processed.wrapping_add(len as u32);
 
         let body = if self.index == Index::_0 {
             bytes
@@ -126,9 +255,7 @@ impl ::Hasher for Hasher {
                 // let (head, body) = bytes.split_at(4 - index);
                 let mid = 4 - index;
                 let head = unsafe { slice::from_raw_parts(bytes.as_ptr(), mid) };
-                let body = unsafe {
-                    slice::from_raw_parts(bytes.as_ptr().offset(mid as isize), len - mid)
-                };
+                let body = unsafe { slice::from_raw_parts(bytes.as_ptr().add(mid), len - mid) };
 
                 // NOTE(unsafe) avoid calling `memcpy` on a 0-3 byte copy
                 // self.buf.bytes[index..].copy_from_slice(head);
@@ -167,6 +294,11 @@ impl ::Hasher for Hasher {
 
         // self.push(tail);
     }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
 }
 
 const C1: u32 = 0xcc9e2d51;
@@ -187,3 +319,12 @@ fn pre_mix(mut block: u32) -> u32 {
     block = block.wrapping_mul(C2);
     block
 }
+
+// finalization mix, `fmix32` in the reference implementation; also used by other hashers
+pub fn fmix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
@@ -0,0 +1,46 @@
+/// Bob Jenkins' one-at-a-time hasher
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, JenkinsOaatHasher};
+///
+/// let mut hasher = JenkinsOaatHasher::default();
+/// hasher.write(b"a");
+/// assert_eq!(hasher.finish(), 0xca2e9442);
+///
+/// let mut hasher = JenkinsOaatHasher::default();
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0x519e91f5);
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        let mut state = self.state;
+        state = state.wrapping_add(state << 3);
+        state ^= state >> 11;
+        state = state.wrapping_add(state << 15);
+        state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = self.state.wrapping_add(u32::from(*byte));
+            self.state = self.state.wrapping_add(self.state << 10);
+            self.state ^= self.state >> 6;
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,112 @@
+/// A `Hasher` that feeds its input to two hashers at once
+///
+/// This computes two independent hashes of a key, e.g. for cuckoo hashing, in a single pass over
+/// the key. `finish` returns the hash of the first hasher; [`finish_pair`] returns both.
+///
+/// [`finish_pair`]: struct.Pair.html#method.finish_pair
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher, Murmur3Hasher, Pair};
+///
+/// let key = ("sensor", 42u16);
+///
+/// let mut pair = Pair::new(FnvHasher::default(), Murmur3Hasher::default());
+/// key.hash(&mut pair);
+///
+/// let mut fnv = FnvHasher::default();
+/// key.hash(&mut fnv);
+///
+/// let mut murmur3 = Murmur3Hasher::default();
+/// key.hash(&mut murmur3);
+///
+/// assert_eq!(pair.finish_pair(), (fnv.finish(), murmur3.finish()));
+/// assert_eq!(pair.finish(), fnv.finish());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pair<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Pair<A, B>
+where
+    A: ::Hasher,
+    B: ::Hasher,
+{
+    /// Combines the given hashers
+    pub fn new(a: A, b: B) -> Self {
+        Pair { a, b }
+    }
+
+    /// Returns the hashes of both hashers
+    pub fn finish_pair(&self) -> (u32, u32) {
+        (self.a.finish(), self.b.finish())
+    }
+
+    /// Returns the inner hashers
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A, B> ::generic::HasherN for Pair<A, B>
+where
+    A: ::Hasher,
+    B: ::Hasher,
+{
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.a.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.a.write(bytes);
+        self.b.write(bytes);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.a.write_u8(i);
+        self.b.write_u8(i);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.a.write_u16(i);
+        self.b.write_u16(i);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.a.write_u32(i);
+        self.b.write_u32(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.a.write_u64(i);
+        self.b.write_u64(i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.a.write_u128(i);
+        self.b.write_u128(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.a.write_usize(i);
+        self.b.write_usize(i);
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        self.a.is_split_insensitive() && self.b.is_split_insensitive()
+    }
+}
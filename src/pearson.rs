@@ -0,0 +1,110 @@
+use byteorder::{ByteOrder, BE};
+
+// permutation table from RFC 3074
+const TABLE: [u8; 256] = [
+    251, 175, 119, 215, 81, 14, 79, 191, 103, 49, 181, 143, 186, 157, 0, 232, 31, 32, 55, 60, 152,
+    58, 17, 237, 174, 70, 160, 144, 220, 90, 57, 223, 59, 3, 18, 140, 111, 166, 203, 196, 134, 243,
+    124, 95, 222, 179, 197, 65, 180, 48, 36, 15, 107, 46, 233, 130, 165, 30, 123, 161, 209, 23, 97,
+    16, 40, 91, 219, 61, 100, 10, 210, 109, 250, 127, 22, 138, 29, 108, 244, 67, 207, 9, 178, 204,
+    74, 98, 126, 249, 167, 116, 34, 77, 193, 200, 121, 5, 20, 113, 71, 35, 128, 13, 182, 94, 25,
+    226, 227, 199, 75, 27, 41, 245, 230, 224, 43, 225, 177, 26, 155, 150, 212, 142, 218, 115, 241,
+    73, 88, 105, 39, 114, 62, 255, 192, 201, 145, 214, 168, 158, 221, 148, 154, 122, 12, 84, 82,
+    163, 44, 139, 228, 236, 205, 242, 217, 11, 187, 146, 159, 64, 86, 239, 195, 42, 106, 198, 118,
+    112, 184, 172, 87, 2, 173, 117, 176, 229, 247, 253, 137, 185, 99, 164, 102, 147, 45, 66, 231,
+    52, 141, 211, 194, 206, 246, 238, 56, 110, 78, 248, 63, 240, 189, 93, 92, 51, 53, 183, 19, 171,
+    72, 50, 33, 104, 101, 69, 8, 252, 83, 120, 76, 135, 85, 54, 202, 125, 188, 213, 96, 235, 136,
+    208, 162, 129, 190, 132, 156, 38, 47, 1, 7, 254, 24, 4, 216, 131, 89, 21, 28, 133, 37, 153,
+    149, 80, 170, 68, 6, 169, 234, 151,
+];
+
+/// Pearson hasher extended to 32 bits
+///
+/// The input is run through the permutation table four times, each pass starting from a different
+/// offset of the first byte (`+0` through `+3`). The four resulting bytes are concatenated, the
+/// first pass ending up in the most significant byte. The empty input hashes to `0`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, PearsonHasher};
+///
+/// let mut hasher = PearsonHasher::default();
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0xb9c0efd0);
+///
+/// // the identity permutation
+/// let mut table = [0; 256];
+/// for (i, entry) in table.iter_mut().enumerate() {
+///     *entry = i as u8;
+/// }
+///
+/// let mut hasher = PearsonHasher::with_table(table);
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0x62636061);
+/// ```
+pub struct Hasher {
+    started: bool,
+    state: [u8; 4],
+    table: [u8; 256],
+}
+
+impl Hasher {
+    /// Creates a hasher that uses the given permutation `table`
+    ///
+    /// `table` should be a permutation of the values `0..=255`; other tables are accepted but
+    /// produce a poorly distributed hash.
+    pub fn with_table(table: [u8; 256]) -> Self {
+        Hasher {
+            started: false,
+            state: [0; 4],
+            table,
+        }
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_table(TABLE)
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        if self.started {
+            BE::read_u32(&self.state)
+        } else {
+            0
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+
+        if !self.started {
+            match bytes.split_first() {
+                Some((first, rest)) => {
+                    for (i, state) in self.state.iter_mut().enumerate() {
+                        *state = self.table[usize::from(first.wrapping_add(i as u8))];
+                    }
+                    self.started = true;
+                    bytes = rest;
+                }
+                None => return,
+            }
+        }
+
+        for byte in bytes {
+            for state in self.state.iter_mut() {
+                *state = self.table[usize::from(*state ^ *byte)];
+            }
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,102 @@
+use wyhash32;
+
+// the prime modulus, `2^31 - 1`
+const P: u32 = 0x7fff_ffff;
+
+/// Rabin-Karp rolling hash
+///
+/// The hash of the window `[c0, c1, .., cn-1]` is `c0 * base^(n-1) + c1 * base^(n-2) + .. + cn-1`
+/// modulo the prime `2^31 - 1`. The modular multiplication is done with 32-bit operations only.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::RabinKarp;
+///
+/// let text = b"abracadabra";
+///
+/// // hash the first window directly ..
+/// let mut rolling = RabinKarp::new(4, 257);
+/// for byte in &text[..4] {
+///     rolling.push(*byte);
+/// }
+///
+/// // .. then slide it over to the last window
+/// for i in 4..text.len() {
+///     rolling.roll(text[i - 4], text[i]);
+/// }
+///
+/// let mut last = RabinKarp::new(4, 257);
+/// for byte in &text[text.len() - 4..] {
+///     last.push(*byte);
+/// }
+///
+/// assert_eq!(rolling.finish(), last.finish());
+/// ```
+pub struct RabinKarp {
+    base: u32,
+    // `base^(window - 1)`; the weight of the oldest byte in the window
+    out_factor: u32,
+    state: u32,
+}
+
+impl RabinKarp {
+    /// Creates a rolling hash over windows of `window` bytes that uses the given `base`
+    ///
+    /// `base` is reduced modulo `2^31 - 1`; it should be larger than 255.
+    pub fn new(window: usize, base: u32) -> Self {
+        let base = reduce(0, base);
+
+        let mut out_factor = 1;
+        for _ in 1..window {
+            out_factor = mul_mod(out_factor, base);
+        }
+
+        RabinKarp {
+            base,
+            out_factor,
+            state: 0,
+        }
+    }
+
+    /// Appends `byte` to the window
+    ///
+    /// Use this to fill the first window; once it is full use `roll` instead.
+    pub fn push(&mut self, byte: u8) {
+        self.state = add_mod(mul_mod(self.state, self.base), u32::from(byte));
+    }
+
+    /// Slides the window by one byte: removes the oldest byte, `out`, and appends `in_`
+    pub fn roll(&mut self, out: u8, in_: u8) {
+        let out = mul_mod(u32::from(out), self.out_factor);
+        self.state = add_mod(self.state, P - out);
+        self.push(in_);
+    }
+
+    /// Returns the hash of the current window
+    pub fn finish(&self) -> u32 {
+        self.state
+    }
+}
+
+fn add_mod(a: u32, b: u32) -> u32 {
+    // both operands are below `2^31` so this can't overflow
+    reduce(0, a + b)
+}
+
+fn mul_mod(a: u32, b: u32) -> u32 {
+    let (low, high) = wyhash32::mul_wide(a, b);
+    reduce(high, low)
+}
+
+// reduces the 64-bit value `high:low` modulo `2^31 - 1`
+fn reduce(high: u32, low: u32) -> u32 {
+    // 2^31 = 1 (mod P) so the bits above bit 30 can be folded onto the lower bits
+    let mut x = (low & P) + ((high << 1) | (low >> 31));
+    x = (x & P) + (x >> 31);
+    if x >= P {
+        x - P
+    } else {
+        x
+    }
+}
@@ -0,0 +1,33 @@
+/// A string that is hashed as its UTF-8 bytes only, without the `0xff` terminator of `str`
+///
+/// The terminator keeps adjacent strings apart, e.g. `("ab", "c")` and `("a", "bc")` hash
+/// differently, so hashing a `str` is the right choice for keys of this crate's own data
+/// structures. Use `RawStr` when the hash has to match one computed by another system over the
+/// raw bytes of the string, like a textbook FNV-1a, and keep in mind that consecutive `RawStr`s
+/// then hash like their concatenation.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher, RawStr};
+///
+/// let mut hasher = FnvHasher::default();
+/// RawStr("abc").hash(&mut hasher);
+/// assert_eq!(hasher.finish(), 0x1a47e90b);
+/// assert_eq!(hasher.finish(), hash32::fnv1a(b"abc"));
+///
+/// let mut terminated = FnvHasher::default();
+/// "abc".hash(&mut terminated);
+/// assert_ne!(terminated.finish(), hasher.finish());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RawStr<'a>(pub &'a str);
+
+impl<'a> ::Hash for RawStr<'a> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: ::Hasher,
+    {
+        state.write(self.0.as_bytes())
+    }
+}
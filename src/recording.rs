@@ -0,0 +1,84 @@
+/// Maximum number of bytes a `RecordingHasher` can record
+pub const CAPACITY: usize = 256;
+
+/// A hasher that records the bytes it is fed, for testing `Hash` implementations
+///
+/// All the bytes passed to `write`, up to [`CAPACITY`] bytes, are stored in a fixed size buffer so
+/// no allocator is needed. [`recorded`] returns them, which makes it possible to assert the exact
+/// encoding of a `Hash` implementation rather than just comparing hashes. `finish` returns the
+/// FNV-1a hash of the recorded bytes.
+///
+/// Only available with the `test` feature.
+///
+/// [`CAPACITY`]: constant.RECORDING_HASHER_CAPACITY.html
+/// [`recorded`]: struct.RecordingHasher.html#method.recorded
+///
+/// # Panics
+///
+/// `write` panics if the total input exceeds [`CAPACITY`] bytes.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hash, Hasher, RecordingHasher};
+///
+/// let mut hasher = RecordingHasher::default();
+/// 0x0403_0201u32.hash(&mut hasher);
+/// assert_eq!(hasher.recorded(), [1, 2, 3, 4]);
+///
+/// let mut hasher = RecordingHasher::default();
+/// "ab".hash(&mut hasher);
+/// (1u8, true).hash(&mut hasher);
+/// assert_eq!(hasher.recorded(), [b'a', b'b', 0xff, 1, 1]);
+/// assert_eq!(hasher.finish(), hash32::fnv1a(&[b'a', b'b', 0xff, 1, 1]));
+/// ```
+#[derive(Clone)]
+pub struct Hasher {
+    buf: [u8; CAPACITY],
+    // number of bytes in `buf`
+    len: usize,
+}
+
+impl Hasher {
+    /// Returns the bytes fed to this hasher so far
+    pub fn recorded(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher {
+            buf: [0; CAPACITY],
+            len: 0,
+        }
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.len
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        ::fnv1a(self.recorded())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        assert!(end <= CAPACITY, "RecordingHasher capacity exceeded");
+
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,87 @@
+/// sdbm hasher
+///
+/// Each byte is mixed in as `hash = byte + (hash << 6) + (hash << 16) - hash`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, SdbmHasher};
+///
+/// let mut hasher = SdbmHasher::default();
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0x28d19932);
+///
+/// let mut hasher = SdbmHasher::default();
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0x8ca77173);
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = u32::from(*byte)
+                .wrapping_add(self.state << 6)
+                .wrapping_add(self.state << 16)
+                .wrapping_sub(self.state);
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Hasher, SdbmHasher};
+
+    const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    fn hash(bytes: &[u8]) -> u32 {
+        let mut hasher = SdbmHasher::default();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn vectors() {
+        assert_eq!(hash(b""), 0);
+        assert_eq!(hash(b"a"), 0x61);
+        assert_eq!(hash(b"ab"), 0x0061_1841);
+    }
+
+    // `(hash << 6) + (hash << 16) - hash` is the usual shift form of `hash * 65599`
+    #[test]
+    fn matches_multiplicative_form() {
+        for len in 0..=DATA.len() {
+            let expected = DATA[..len].iter().fold(0u32, |hash, byte| {
+                hash.wrapping_mul(65599).wrapping_add(u32::from(*byte))
+            });
+            assert_eq!(hash(&DATA[..len]), expected);
+        }
+    }
+
+    #[test]
+    fn split_writes() {
+        for i in 0..=DATA.len() {
+            let (head, tail) = DATA.split_at(i);
+            let mut hasher = SdbmHasher::default();
+            hasher.write(head);
+            hasher.write(tail);
+            assert_eq!(hasher.finish(), hash(DATA), "split at {}", i);
+        }
+    }
+}
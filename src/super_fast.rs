@@ -0,0 +1,143 @@
+use byteorder::{ByteOrder, LE};
+
+/// Paul Hsieh's SuperFastHash hasher
+///
+/// The reference implementation seeds the hash with the length of the input, which is not known
+/// up front when hashing incrementally. This hasher instead takes the initial value as a seed:
+/// `SuperFastHasher::with_seed(len)` reproduces the reference `SuperFastHash(data, len)` when the
+/// total length is known ahead of time, and `Default` uses a seed of `0`. Like the reference, the
+/// empty input hashes to `0`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, SuperFastHasher};
+///
+/// let mut hasher = SuperFastHasher::with_seed(5);
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0xb09dc87b);
+///
+/// let mut hasher = SuperFastHasher::default();
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0x13842ac5);
+/// ```
+pub struct Hasher {
+    buf: [u8; 4],
+    // number of bytes in `buf`
+    index: usize,
+    started: bool,
+    state: u32,
+}
+
+impl Hasher {
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher {
+            buf: [0; 4],
+            index: 0,
+            started: false,
+            state: seed,
+        }
+    }
+
+    fn process_block(&mut self, block: &[u8]) {
+        self.state = self.state.wrapping_add(u32::from(LE::read_u16(block)));
+        let tmp = (u32::from(LE::read_u16(&block[2..])) << 11) ^ self.state;
+        self.state = (self.state << 16) ^ tmp;
+        self.state = self.state.wrapping_add(self.state >> 11);
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        if !self.started {
+            return 0;
+        }
+
+        let mut state = self.state;
+
+        // tail
+        let tail = &self.buf[..self.index];
+        match tail.len() {
+            3 => {
+                state = state.wrapping_add(u32::from(LE::read_u16(tail)));
+                state ^= state << 16;
+                state ^= (i32::from(tail[2] as i8) as u32) << 18;
+                state = state.wrapping_add(state >> 11);
+            }
+            2 => {
+                state = state.wrapping_add(u32::from(LE::read_u16(tail)));
+                state ^= state << 11;
+                state = state.wrapping_add(state >> 17);
+            }
+            1 => {
+                state = state.wrapping_add(i32::from(tail[0] as i8) as u32);
+                state ^= state << 10;
+                state = state.wrapping_add(state >> 1);
+            }
+            _ => {}
+        }
+
+        // avalanche
+        state ^= state << 3;
+        state = state.wrapping_add(state >> 5);
+        state ^= state << 4;
+        state = state.wrapping_add(state >> 17);
+        state ^= state << 25;
+        state = state.wrapping_add(state >> 6);
+
+        state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        self.started = true;
+
+        let mut bytes = bytes;
+        if self.index != 0 {
+            let mid = (4 - self.index).min(bytes.len());
+            let (head, rest) = bytes.split_at(mid);
+            self.buf[self.index..self.index + mid].copy_from_slice(head);
+            self.index += mid;
+            bytes = rest;
+
+            if self.index < 4 {
+                return;
+            }
+
+            let buf = self.buf;
+            self.process_block(&buf);
+            self.index = 0;
+        }
+
+        for block in bytes.chunks(4) {
+            if block.len() == 4 {
+                self.process_block(block);
+            } else {
+                self.buf[..block.len()].copy_from_slice(block);
+                self.index = block.len();
+            }
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,56 @@
+use {Hasher, Murmur3Hasher};
+
+/// An order-independent combination of the hashes of a collection's elements
+///
+/// This hashes set-like collections, where the order of the elements doesn't matter, without
+/// sorting them first: hash each element on its own and [`insert`] its hash. The accumulator keeps
+/// the wrapping sum and the XOR of the hashes, both commutative, plus the number of elements.
+/// [`finish`] mixes the three together with MurmurHash3, so the result doesn't depend on the order
+/// of the insertions.
+///
+/// Using both the sum and the XOR reduces cancellations: inserting the same hash twice cancels out
+/// in the XOR but not in the sum. Collisions between different collections are still more likely
+/// than with an ordered hash, as with any commutative combination.
+///
+/// [`insert`]: struct.UnorderedCombine.html#method.insert
+/// [`finish`]: struct.UnorderedCombine.html#method.finish
+///
+/// # Examples
+///
+/// ```
+/// use hash32::UnorderedCombine;
+///
+/// let mut a = UnorderedCombine::default();
+/// a.insert(hash32::fnv1a(b"a"));
+/// a.insert(hash32::fnv1a(b"b"));
+///
+/// let mut b = UnorderedCombine::default();
+/// b.insert(hash32::fnv1a(b"b"));
+/// b.insert(hash32::fnv1a(b"a"));
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UnorderedCombine {
+    sum: u32,
+    xor: u32,
+    len: u32,
+}
+
+impl UnorderedCombine {
+    /// Folds the hash of an element into the accumulator
+    pub fn insert(&mut self, item_hash: u32) {
+        self.sum = self.sum.wrapping_add(item_hash);
+        self.xor ^= item_hash;
+        self.len = self.len.wrapping_add(1);
+    }
+
+    /// Returns the combined hash of the elements inserted so far
+    pub fn finish(&self) -> u32 {
+        let mut hasher = Murmur3Hasher::default();
+        hasher.write_u32(self.sum);
+        hasher.write_u32(self.xor);
+        hasher.write_u32(self.len);
+        hasher.finish()
+    }
+}
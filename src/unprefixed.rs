@@ -0,0 +1,54 @@
+/// A slice that is hashed without its length prefix
+///
+/// `[T]` feeds its length to the hasher before its elements; `Unprefixed` feeds only the elements,
+/// in order, as if each one had been hashed on its own. Hashing `Unprefixed(a)` and then
+/// `Unprefixed(b)` is therefore the same as hashing `Unprefixed` of the concatenation of `a` and
+/// `b`, which allows hashing a sequence that arrives in pieces.
+///
+/// The price is that the boundaries between slices are lost: `[1], [2, 3]` and `[1, 2], [3]`
+/// collide when hashed this way. Only use `Unprefixed` when that's intended, or when the length
+/// of the slices is fixed or hashed separately.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{FnvHasher, Hash, Hasher, Unprefixed};
+///
+/// let mut pieces = FnvHasher::default();
+/// Unprefixed(&[1u16, 2][..]).hash(&mut pieces);
+/// Unprefixed(&[3u16][..]).hash(&mut pieces);
+///
+/// let mut joined = FnvHasher::default();
+/// Unprefixed(&[1u16, 2, 3][..]).hash(&mut joined);
+///
+/// assert_eq!(pieces.finish(), joined.finish());
+///
+/// // plain slices keep their length prefixes so the pieces don't hash like the whole
+/// let mut pieces = FnvHasher::default();
+/// [1u16, 2][..].hash(&mut pieces);
+/// [3u16][..].hash(&mut pieces);
+///
+/// let mut joined = FnvHasher::default();
+/// [1u16, 2, 3][..].hash(&mut joined);
+///
+/// assert_ne!(pieces.finish(), joined.finish());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Unprefixed<'a, T>(pub &'a [T])
+where
+    T: 'a;
+
+impl<'a, T> ::Hash for Unprefixed<'a, T>
+where
+    T: 'a + ::Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: ::Hasher,
+    {
+        // element by element, not `hash_slice`, which may pack the elements (e.g. `bool`)
+        for x in self.0 {
+            x.hash(state);
+        }
+    }
+}
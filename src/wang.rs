@@ -0,0 +1,132 @@
+/// Thomas Wang's integer hasher
+///
+/// The input is packed into little endian 32-bit words, each of which is XOR-ed into the state
+/// before running Wang's `hash32shift` mix over it, in its seven step form that uses shifts, adds
+/// and XORs only. An incomplete trailing word is padded with zero bytes and mixed in by `finish`.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, WangHasher};
+///
+/// let mut hasher = WangHasher::default();
+/// hasher.write(&1u32.to_le_bytes());
+/// assert_eq!(hasher.finish(), 0x12d60bf6);
+///
+/// let mut hasher = WangHasher::default();
+/// hasher.write(&0xdeadbeefu32.to_le_bytes());
+/// assert_eq!(hasher.finish(), 0x92da7565);
+/// ```
+#[derive(Default)]
+pub struct Hasher {
+    state: u32,
+    // incomplete word; its bytes are stored in little endian order
+    tail: u32,
+    // number of bytes in `tail`
+    count: u32,
+}
+
+impl Hasher {
+    fn push(&mut self, byte: u8) {
+        self.tail |= u32::from(byte) << (self.count * 8);
+        self.count += 1;
+
+        if self.count == 4 {
+            self.state = mix(self.state ^ self.tail);
+            self.tail = 0;
+            self.count = 0;
+        }
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        if self.count == 0 {
+            self.state
+        } else {
+            mix(self.state ^ self.tail)
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.push(*byte);
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+// `hash32shift`, with its `key * 2057` written as shifts and adds
+fn mix(mut key: u32) -> u32 {
+    key = (!key).wrapping_add(key << 15);
+    key ^= key >> 12;
+    key = key.wrapping_add(key << 2);
+    key ^= key >> 4;
+    key = key.wrapping_add(key << 3).wrapping_add(key << 11);
+    key ^= key >> 16;
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use {Hasher, WangHasher};
+
+    fn hash(key: u32) -> u32 {
+        let mut hasher = WangHasher::default();
+        hasher.write_u32(key);
+        hasher.finish()
+    }
+
+    #[test]
+    fn mix_is_hash32shift() {
+        let mut key = 0x9e37_79b9u32;
+        for _ in 0..1_000 {
+            // xorshift32
+            key ^= key << 13;
+            key ^= key >> 17;
+            key ^= key << 5;
+
+            let mut k = (!key).wrapping_add(key << 15);
+            k ^= k >> 12;
+            k = k.wrapping_add(k << 2);
+            k ^= k >> 4;
+            k = k.wrapping_mul(2057);
+            k ^= k >> 16;
+            assert_eq!(hash(key), k);
+        }
+    }
+
+    #[test]
+    fn bit_diffusion() {
+        let mut keys = [0; 16];
+        keys[..4].copy_from_slice(&[0, 1, 0xdead_beef, 0xffff_ffff]);
+        let mut x = 0x9e37_79b9u32;
+        for key in keys[4..].iter_mut() {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            *key = x;
+        }
+
+        let mut total = 0;
+        for &key in &keys {
+            for bit in 0..32 {
+                let changed = (hash(key) ^ hash(key ^ (1 << bit))).count_ones();
+                assert!(changed >= 3, "{:#x} bit {}: {} bits", key, bit, changed);
+                total += changed;
+            }
+        }
+
+        // ideally half of the 32 output bits change on average
+        let average = total as f32 / (keys.len() * 32) as f32;
+        assert!(average > 14.0 && average < 18.0, "{}", average);
+    }
+}
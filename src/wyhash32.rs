@@ -0,0 +1,220 @@
+use byteorder::{ByteOrder, LE};
+
+// the `_wymix32` constants of the reference 32-bit wyhash
+const C0: u32 = 0x53c5ca59;
+const C1: u32 = 0x74743c1b;
+
+/// wyhash-inspired 32-bit hasher
+///
+/// The state is a pair of 32-bit words `(a, b)`. Each 8-byte block is XOR-ed into the pair, which
+/// is then mixed by computing the full 64-bit product `(a ^ 0x53c5ca59) * (b ^ 0x74743c1b)` and
+/// storing its low and high halves back into `a` and `b`. `finish` XORs the zero padded tail and
+/// the total input length into the pair, mixes it twice and returns `a ^ b`.
+///
+/// Unlike the reference 32-bit wyhash, which needs the input length up front, this construction
+/// can be computed incrementally so its output differs from the reference. The 64-bit product is
+/// built out of 16x16 -> 32-bit partial products so no 64-bit multiplication is ever performed.
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, WyHash32Hasher};
+///
+/// let mut hasher = WyHash32Hasher::with_seed(0);
+/// hasher.write(b"hello");
+/// assert_eq!(hasher.finish(), 0x37adfad9);
+///
+/// let mut hasher = WyHash32Hasher::with_seed(0x9747b28c);
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0x296a332d);
+/// ```
+pub struct Hasher {
+    a: u32,
+    b: u32,
+    buf: [u8; 8],
+    // number of bytes in `buf`
+    index: usize,
+    processed: u32,
+}
+
+impl Hasher {
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher {
+            a: seed,
+            b: 0,
+            buf: [0; 8],
+            index: 0,
+            processed: 0,
+        }
+    }
+
+    fn process_block(&mut self, block: &[u8]) {
+        let (a, b) = mix(
+            self.a ^ LE::read_u32(block),
+            self.b ^ LE::read_u32(&block[4..]),
+        );
+        self.a = a;
+        self.b = b;
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.processed as usize
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        // zero padded tail
+        let mut block = [0; 8];
+        block[..self.index].copy_from_slice(&self.buf[..self.index]);
+
+        let a = self.a ^ LE::read_u32(&block);
+        let b = self.b ^ LE::read_u32(&block[4..]) ^ self.processed;
+        let (a, b) = mix(a, b);
+        let (a, b) = mix(a, b);
+        a ^ b
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.processed = self.processed.wrapping_add(bytes.len() as u32);
+
+        let mut bytes = bytes;
+        if self.index != 0 {
+            let mid = (8 - self.index).min(bytes.len());
+            let (head, rest) = bytes.split_at(mid);
+            self.buf[self.index..self.index + mid].copy_from_slice(head);
+            self.index += mid;
+            bytes = rest;
+
+            if self.index < 8 {
+                return;
+            }
+
+            let buf = self.buf;
+            self.process_block(&buf);
+            self.index = 0;
+        }
+
+        for block in bytes.chunks(8) {
+            if block.len() == 8 {
+                self.process_block(block);
+            } else {
+                self.buf[..block.len()].copy_from_slice(block);
+                self.index = block.len();
+            }
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+fn mix(a: u32, b: u32) -> (u32, u32) {
+    mul_wide(a ^ C0, b ^ C1)
+}
+
+/// Computes the 64-bit product `a * b` as its `(low, high)` halves using only 32-bit operations
+pub fn mul_wide(a: u32, b: u32) -> (u32, u32) {
+    let (a_lo, a_hi) = (a & 0xffff, a >> 16);
+    let (b_lo, b_hi) = (b & 0xffff, b >> 16);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    // the sum of three 16-bit values can't overflow
+    let mid = (lo_lo >> 16) + (lo_hi & 0xffff) + (hi_lo & 0xffff);
+
+    let low = (lo_lo & 0xffff) | (mid << 16);
+    let high = hi_hi + (lo_hi >> 16) + (hi_lo >> 16) + (mid >> 16);
+    (low, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mul_wide;
+    use {Hasher, WyHash32Hasher};
+
+    #[test]
+    fn mul_wide_products() {
+        // (a, b, low, high); the products were computed with arbitrary precision integers
+        let products = [
+            (0, 0xffff_ffff, 0, 0),
+            (1, 0xffff_ffff, 0xffff_ffff, 0),
+            (0xffff_ffff, 0xffff_ffff, 0x0000_0001, 0xffff_fffe),
+            (0x0000_ffff, 0x0000_ffff, 0xfffe_0001, 0x0000_0000),
+            (0xffff_0000, 0x0000_ffff, 0x0001_0000, 0x0000_fffe),
+            // carries out of the middle partial products
+            (0x0001_ffff, 0x0001_ffff, 0xfffc_0001, 0x0000_0003),
+            (0x8000_ffff, 0xffff_8000, 0x8000_8000, 0x8000_bffe),
+            (0x1234_5678, 0x9abc_def0, 0x242d_2080, 0x0b00_ea4e),
+            (0x53c5_ca59, 0x7474_3c1b, 0x359d_3363, 0x261b_a8fb),
+        ];
+        for &(a, b, low, high) in &products {
+            assert_eq!(mul_wide(a, b), (low, high), "{:#x} * {:#x}", a, b);
+            assert_eq!(mul_wide(b, a), (low, high), "{:#x} * {:#x}", b, a);
+        }
+    }
+
+    #[test]
+    fn mul_wide_matches_u64() {
+        let mut x = 0x9e37_79b9u32;
+        for _ in 0..10_000 {
+            // xorshift32
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            let (a, b) = (x, x.rotate_left(16) ^ 0xdead_beef);
+
+            let product = u64::from(a) * u64::from(b);
+            assert_eq!(mul_wide(a, b), (product as u32, (product >> 32) as u32));
+        }
+    }
+
+    #[test]
+    fn streaming_equals_one_shot() {
+        let mut data = [0; 100];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 31 + 7) as u8;
+        }
+        for len in 0..data.len() {
+            let mut one_shot = WyHash32Hasher::with_seed(42);
+            one_shot.write(&data[..len]);
+
+            for chunk in 1..10 {
+                let mut streaming = WyHash32Hasher::with_seed(42);
+                for piece in data[..len].chunks(chunk) {
+                    streaming.write(piece);
+                }
+                assert_eq!(
+                    streaming.finish(),
+                    one_shot.finish(),
+                    "len {} chunk {}",
+                    len,
+                    chunk
+                );
+            }
+        }
+    }
+}
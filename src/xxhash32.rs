@@ -0,0 +1,158 @@
+use byteorder::{ByteOrder, LE};
+
+const PRIME1: u32 = 0x9e3779b1;
+const PRIME2: u32 = 0x85ebca77;
+const PRIME3: u32 = 0xc2b2ae3d;
+const PRIME4: u32 = 0x27d4eb2f;
+const PRIME5: u32 = 0x165667b1;
+
+/// 32-bit xxHash (XXH32) hasher
+///
+/// # Examples
+///
+/// ```
+/// use hash32::{Hasher, XxHash32};
+///
+/// let mut hasher = XxHash32::default();
+/// hasher.write(b"Nobody inspects the spammish repetition");
+/// assert_eq!(hasher.finish(), 0xe2293b2f);
+///
+/// let mut hasher = XxHash32::with_seed(0x9747b28c);
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish(), 0xc8579d72);
+/// ```
+pub struct Hasher {
+    acc: [u32; 4],
+    buf: [u8; 16],
+    // number of bytes in `buf`
+    index: usize,
+    // whether at least one 16-byte stripe has been processed
+    large: bool,
+    total_len: u32,
+}
+
+impl Hasher {
+    /// Creates a hasher that starts from the given `seed`
+    pub fn with_seed(seed: u32) -> Self {
+        Hasher {
+            acc: [
+                seed.wrapping_add(PRIME1).wrapping_add(PRIME2),
+                seed.wrapping_add(PRIME2),
+                seed,
+                seed.wrapping_sub(PRIME1),
+            ],
+            buf: [0; 16],
+            index: 0,
+            large: false,
+            total_len: 0,
+        }
+    }
+
+    fn process_stripe(&mut self, stripe: &[u8]) {
+        for (i, acc) in self.acc.iter_mut().enumerate() {
+            *acc = round(*acc, LE::read_u32(&stripe[i * 4..]));
+        }
+        self.large = true;
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::with_seed(0)
+    }
+}
+
+impl ::SeededHasher for Hasher {
+    fn with_seed(seed: u32) -> Self {
+        Hasher::with_seed(seed)
+    }
+}
+
+impl ::CountingHasher for Hasher {
+    #[inline]
+    fn bytes_written(&self) -> usize {
+        self.total_len as usize
+    }
+}
+
+impl ::generic::HasherN for Hasher {
+    type Output = u32;
+
+    fn finish(&self) -> u32 {
+        let mut state = if self.large {
+            self.acc[0]
+                .rotate_left(1)
+                .wrapping_add(self.acc[1].rotate_left(7))
+                .wrapping_add(self.acc[2].rotate_left(12))
+                .wrapping_add(self.acc[3].rotate_left(18))
+        } else {
+            // no stripe has been processed so the third accumulator still holds the seed
+            self.acc[2].wrapping_add(PRIME5)
+        };
+
+        state = state.wrapping_add(self.total_len);
+
+        // tail
+        for word in self.buf[..self.index].chunks(4) {
+            if word.len() == 4 {
+                state = state.wrapping_add(LE::read_u32(word).wrapping_mul(PRIME3));
+                state = state.rotate_left(17).wrapping_mul(PRIME4);
+            } else {
+                for byte in word {
+                    state = state.wrapping_add(u32::from(*byte).wrapping_mul(PRIME5));
+                    state = state.rotate_left(11).wrapping_mul(PRIME1);
+                }
+            }
+        }
+
+        // avalanche
+        state ^= state >> 15;
+        state = state.wrapping_mul(PRIME2);
+        state ^= state >> 13;
+        state = state.wrapping_mul(PRIME3);
+        state ^= state >> 16;
+
+        state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(bytes.len() as u32);
+
+        let mut bytes = bytes;
+        if self.index != 0 {
+            let mid = (16 - self.index).min(bytes.len());
+            let (head, rest) = bytes.split_at(mid);
+            self.buf[self.index..self.index + mid].copy_from_slice(head);
+            self.index += mid;
+            bytes = rest;
+
+            if self.index < 16 {
+                return;
+            }
+
+            let buf = self.buf;
+            self.process_stripe(&buf);
+            self.index = 0;
+        }
+
+        for stripe in bytes.chunks(16) {
+            if stripe.len() == 16 {
+                self.process_stripe(stripe);
+            } else {
+                self.buf[..stripe.len()].copy_from_slice(stripe);
+                self.index = stripe.len();
+            }
+        }
+    }
+
+    #[inline]
+    fn is_split_insensitive(&self) -> bool {
+        true
+    }
+}
+
+fn round(acc: u32, input: u32) -> u32 {
+    acc.wrapping_add(input.wrapping_mul(PRIME2))
+        .rotate_left(13)
+        .wrapping_mul(PRIME1)
+}